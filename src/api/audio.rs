@@ -0,0 +1,102 @@
+//! Range handling for the audio stream proxy
+//!
+//! Players seek with `Range: bytes=...` requests. Only a single well-formed
+//! byte range is forwarded to the origin (with its `If-Range`); anything else
+//! is dropped, so the origin answers with the whole file, as RFC 9110 allows
+//! for ranges a server does not support.
+
+/// Origin response headers passed through to the client
+pub const PASSTHROUGH_HEADERS: [&str; 5] = [
+    "Content-Length",
+    "Content-Range",
+    "Accept-Ranges",
+    "ETag",
+    "Last-Modified",
+];
+
+/// `Cache-Control` for full responses (24 hours)
+pub const FULL_CACHE_CONTROL: &str = "public, max-age=86400";
+
+/// `Cache-Control` for partial and unsatisfiable-range responses
+pub const PARTIAL_CACHE_CONTROL: &str = "private, no-store";
+
+/// A single byte range from a `Range` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// `bytes=start-` or `bytes=start-end` (inclusive)
+    From { start: u64, end: Option<u64> },
+    /// `bytes=-length`: the last `length` bytes
+    Suffix(u64),
+}
+
+impl ByteRange {
+    /// Parse a `Range` header holding one byte range
+    /// Multiple ranges, other units and malformed ranges give `None`
+    pub fn parse(header: &str) -> Option<Self> {
+        let (unit, spec) = header.trim().split_once('=')?;
+        if !unit.trim().eq_ignore_ascii_case("bytes") || spec.contains(',') {
+            return None;
+        }
+        let (start, end) = spec.trim().split_once('-')?;
+        let number = |text: &str| -> Option<u64> {
+            let text = text.trim();
+            if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            text.parse().ok()
+        };
+        if start.trim().is_empty() {
+            return match number(end)? {
+                0 => None,
+                length => Some(Self::Suffix(length)),
+            };
+        }
+        let start = number(start)?;
+        let end = if end.trim().is_empty() {
+            None
+        } else {
+            Some(number(end)?)
+        };
+        match end {
+            Some(end) if end < start => None,
+            _ => Some(Self::From { start, end }),
+        }
+    }
+}
+
+impl std::fmt::Display for ByteRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::From {
+                start,
+                end: Some(end),
+            } => write!(f, "bytes={}-{}", start, end),
+            Self::From { start, end: None } => write!(f, "bytes={}-", start),
+            Self::Suffix(length) => write!(f, "bytes=-{}", length),
+        }
+    }
+}
+
+/// Request headers to send to the audio origin, read with `header`
+/// `If-Range` is only meaningful with a range, so it is dropped without one
+pub fn forwarded_headers(header: impl Fn(&str) -> Option<String>) -> Vec<(&'static str, String)> {
+    let Some(range) = header("Range").as_deref().and_then(ByteRange::parse) else {
+        return Vec::new();
+    };
+    let mut headers = vec![("Range", range.to_string())];
+    if let Some(if_range) = header("If-Range") {
+        headers.push(("If-Range", if_range));
+    }
+    headers
+}
+
+/// `Cache-Control` for an audio response with the origin's `status`
+/// Only full (200) responses are shared; a cached 206 or 416 would be
+/// replayed to clients asking for other ranges
+pub fn cache_control(status: u16) -> &'static str {
+    if status == 200 {
+        FULL_CACHE_CONTROL
+    } else {
+        PARTIAL_CACHE_CONTROL
+    }
+}
@@ -1,106 +1,392 @@
 //! High-level API client for CourtListener
 
+use crate::api::coalescer::fetch_via_durable_object;
+use crate::api::rate_limit::acquire_for_env;
 use crate::api::request::create_api_request;
+use crate::api::retry::{Retried, RetryPolicy};
+use crate::api::transport::HttpMethod;
 use crate::cache::{
-    generate_cache_key, get_cache_ttl, get_cached_with_expiry, set_cached_with_expiry,
+    generate_cache_key, is_empty_result, lookup_entry, put_entry_with, put_negative_entry,
+    CacheConfig, CacheDirectives, CacheLookup, CachePartition, CacheStatus, Coalescing, NoopCache,
+    SingleFlight, WorkerCache,
 };
-use worker::*;
+use crate::errors::{ApiError, CourtListenerError, Result};
+use crate::utils::{get_or_create_request_id, log_attempts, log_with_request_id, upstream_error};
+use worker::{Context, Delay, Env, Fetch, Request};
 
 /// API client for fetching data from CourtListener API
 pub struct ApiClient;
 
+/// Result of a cached fetch: the parsed data, how many upstream attempts were
+/// made (0 when served from cache), whether the cache was used, which
+/// cache partition the request belongs to and the endpoint's TTL in seconds
+pub struct Fetched<T> {
+    pub result: Result<T>,
+    pub attempts: u32,
+    pub cache: CacheStatus,
+    pub partition: CachePartition,
+    pub ttl: u64,
+}
+
+impl<T> Fetched<T> {
+    /// Transform the result, keeping the attempt count and cache status
+    pub fn map<U>(self, f: impl FnOnce(Result<T>) -> Result<U>) -> Fetched<U> {
+        Fetched {
+            result: f(self.result),
+            attempts: self.attempts,
+            cache: self.cache,
+            partition: self.partition,
+            ttl: self.ttl,
+        }
+    }
+}
+
 impl ApiClient {
     /// Fetch and parse JSON from CourtListener API with caching
     /// Preserves query parameters from the original request
-    pub async fn fetch_json<T: serde::de::DeserializeOwned>(
+    pub async fn fetch_json<T: serde::de::DeserializeOwned + 'static>(
+        env: &Env,
+        endpoint: &str,
+        req: &Request,
+    ) -> Result<T> {
+        Self::fetch(env, endpoint, req).await.result
+    }
+
+    /// Fetch and parse JSON, reporting upstream attempts and cache status
+    /// Retries follow `RetryPolicy::from_env`
+    /// Without a `Context` there is no background refresh, so stale entries are
+    /// refetched before responding
+    pub async fn fetch<T: serde::de::DeserializeOwned + 'static>(
+        env: &Env,
+        endpoint: &str,
+        req: &Request,
+    ) -> Fetched<T> {
+        Self::fetch_json_internal(env, None, endpoint, req, false).await
+    }
+
+    /// Like `fetch`, but serves stale entries immediately and refreshes them
+    /// with `ctx.wait_until` (stale-while-revalidate)
+    pub async fn fetch_with_context<T: serde::de::DeserializeOwned + 'static>(
+        env: &Env,
+        ctx: &Context,
+        endpoint: &str,
+        req: &Request,
+    ) -> Fetched<T> {
+        Self::fetch_json_internal(env, Some(ctx), endpoint, req, false).await
+    }
+
+    /// Fetch from upstream without reading the cache, then store the response
+    /// Used to pre-warm or refresh cache entries
+    pub async fn refresh<T: serde::de::DeserializeOwned + 'static>(
         env: &Env,
         endpoint: &str,
         req: &Request,
-    ) -> worker::Result<T> {
-        Self::fetch_json_internal(env, endpoint, req).await
+    ) -> Fetched<T> {
+        Self::fetch_json_internal(env, None, endpoint, req, true).await
     }
 
     /// Fetch and parse JSON from CourtListener API with caching and validation
     /// Requires the type to implement `validator::Validate`
     /// Preserves query parameters from the original request
-    pub async fn fetch_json_validated<T>(env: &Env, endpoint: &str, req: &Request) -> worker::Result<T>
+    pub async fn fetch_json_validated<T>(env: &Env, endpoint: &str, req: &Request) -> Result<T>
     where
-        T: serde::de::DeserializeOwned + validator::Validate,
+        T: serde::de::DeserializeOwned + validator::Validate + 'static,
     {
-        let parsed = Self::fetch_json_internal(env, endpoint, req).await?;
-        Self::validate_parsed(&parsed)?;
+        let parsed: T = Self::fetch(env, endpoint, req).await.result?;
+        parsed.validate()?;
         Ok(parsed)
     }
 
     /// Internal method for fetching JSON
-    async fn fetch_json_internal<T: serde::de::DeserializeOwned>(
+    /// `refresh` skips the cache lookup but still stores the response
+    /// Requests with `Cache-Control: no-cache` are treated as refreshes and
+    /// `no-store` bypasses the cache, when `CACHE_HONOR_NO_CACHE` is true
+    async fn fetch_json_internal<T: serde::de::DeserializeOwned + 'static>(
         env: &Env,
+        ctx: Option<&Context>,
         endpoint: &str,
         req: &Request,
-    ) -> worker::Result<T> {
-        // Generate cache key
+        refresh: bool,
+    ) -> Fetched<T> {
+        let config = CacheConfig::from_env(env);
+        let ttl = config.ttl_for(endpoint);
+        let directives = if config.honor_no_cache {
+            let header = |name: &str| req.headers().get(name).ok().flatten();
+            CacheDirectives::parse(
+                header("Cache-Control").as_deref(),
+                header("Pragma").as_deref(),
+            )
+        } else {
+            CacheDirectives::default()
+        };
+        let refresh = refresh || directives.no_cache;
+        let caller_authorization = req.headers().get("Authorization").ok().flatten();
+        let upstream_token = env
+            .secret("COURTLISTENER_API_TOKEN")
+            .ok()
+            .map(|t| t.to_string());
+        let partition = if directives.no_store {
+            CachePartition::Bypass
+        } else {
+            CachePartition::for_request(
+                endpoint,
+                caller_authorization.as_deref(),
+                upstream_token.as_deref(),
+                &config,
+            )
+        };
+
+        // Generate cache key within the caller's partition
         let url = req.url().ok();
         let query = url.as_ref().and_then(|u| u.query());
-        let cache_key = generate_cache_key(endpoint, query);
-        let cache_ttl = get_cache_ttl(endpoint);
+        let Some(cache_key) = partition.key(&generate_cache_key(endpoint, query)) else {
+            let retried = Self::fetch_upstream::<T>(
+                env,
+                &config,
+                endpoint,
+                req,
+                &WorkerCache::None(NoopCache),
+                "",
+            )
+            .await;
+            log_attempts(req, endpoint, retried.attempts, retried.result.is_ok());
+            return Fetched {
+                result: retried.result,
+                attempts: retried.attempts,
+                cache: CacheStatus::Bypass,
+                partition,
+                ttl,
+            };
+        };
+        let cache = WorkerCache::from_config(env, &config);
 
         // Try to get from cache first
-        if let Some(cached_text) = get_cached_with_expiry(env, &cache_key).await {
-            if let Ok(parsed) = serde_json::from_str::<T>(&cached_text) {
-                return Ok(parsed);
+        let lookup = if refresh {
+            CacheLookup::Miss
+        } else {
+            lookup_entry(&cache, &cache_key).await
+        };
+        let (cached, status) = match lookup {
+            CacheLookup::Negative { status, body } => {
+                return Fetched {
+                    result: Err(CourtListenerError::Api(ApiError::from_response(
+                        status, &body, None,
+                    ))),
+                    attempts: 0,
+                    cache: CacheStatus::Hit,
+                    partition,
+                    ttl,
+                };
+            }
+            CacheLookup::Fresh(text) => (Some(text), CacheStatus::Hit),
+            CacheLookup::Stale(text) if ctx.is_some() => (Some(text), CacheStatus::Stale),
+            CacheLookup::Stale(_) | CacheLookup::Miss => (None, CacheStatus::Miss),
+        };
+        if let Some(parsed) = cached.and_then(|text| serde_json::from_str::<T>(&text).ok()) {
+            if let (CacheStatus::Stale, Some(ctx)) = (status, ctx) {
+                Self::refresh_in_background::<T>(
+                    ctx, env, &config, endpoint, req, cache, cache_key,
+                );
             }
+            return Fetched {
+                result: Ok(parsed),
+                attempts: 0,
+                cache: status,
+                partition,
+                ttl,
+            };
         }
 
         // Cache miss or invalid cache, fetch from API
-        let api_req = create_api_request(env, endpoint, req)?;
-        let mut resp = Fetch::Request(api_req).send().await?;
+        let retried =
+            Self::fetch_upstream::<T>(env, &config, endpoint, req, &cache, &cache_key).await;
+        log_attempts(req, endpoint, retried.attempts, retried.result.is_ok());
+        Fetched {
+            result: retried.result,
+            attempts: retried.attempts,
+            cache: CacheStatus::Miss,
+            partition,
+            ttl,
+        }
+    }
+
+    /// Refetch a stale entry after the response has been sent
+    fn refresh_in_background<T: serde::de::DeserializeOwned + 'static>(
+        ctx: &Context,
+        env: &Env,
+        config: &CacheConfig,
+        endpoint: &str,
+        req: &Request,
+        cache: WorkerCache,
+        cache_key: String,
+    ) {
+        // The refresh outlives this request, so it needs owned copies
+        let Ok(req) = req.clone() else {
+            return;
+        };
+        let env = env.clone();
+        let config = config.clone();
+        let endpoint = endpoint.to_string();
+        ctx.wait_until(async move {
+            let retried =
+                Self::fetch_upstream::<T>(&env, &config, &endpoint, &req, &cache, &cache_key).await;
+            if let Err(e) = &retried.result {
+                log_with_request_id(
+                    &get_or_create_request_id(&req),
+                    "WARN",
+                    &format!("Background refresh of {} failed: {}", endpoint, e),
+                );
+            }
+        });
+    }
+
+    /// Fetch from the API and parse the response
+    /// Concurrent fetches for the same cache key share one upstream call, as
+    /// configured by `CacheConfig::coalesce`; callers that receive another
+    /// request's result report 0 attempts
+    async fn fetch_upstream<T: serde::de::DeserializeOwned>(
+        env: &Env,
+        config: &CacheConfig,
+        endpoint: &str,
+        req: &Request,
+        cache: &WorkerCache,
+        cache_key: &str,
+    ) -> Retried<Result<T>> {
+        let retried = Self::fetch_coalesced(env, config, endpoint, req, cache, cache_key).await;
+        retried.map(|result| result.and_then(|text| parse_json(&text)))
+    }
+
+    async fn fetch_coalesced(
+        env: &Env,
+        config: &CacheConfig,
+        endpoint: &str,
+        req: &Request,
+        cache: &WorkerCache,
+        cache_key: &str,
+    ) -> Retried<Result<String>> {
+        thread_local! {
+            static IN_FLIGHT: SingleFlight<Retried<Result<String>>> = SingleFlight::new();
+        }
+
+        // Bypassed requests have no key to share
+        if cache_key.is_empty() || config.coalesce == Coalescing::Off {
+            return Self::fetch_and_store(env, config, endpoint, req, cache, cache_key).await;
+        }
+        if config.coalesce == Coalescing::DurableObject {
+            if let Some(retried) = fetch_via_durable_object(env, endpoint, req, cache_key).await {
+                return retried;
+            }
+        }
+        // Each request fetches in its own context; joiners only receive the body
+        let in_flight = IN_FLIGHT.with(SingleFlight::clone);
+        let (retried, joined) = in_flight
+            .run(cache_key, || {
+                Self::fetch_and_store(env, config, endpoint, req, cache, cache_key)
+            })
+            .await;
+        if joined {
+            log_with_request_id(
+                &get_or_create_request_id(req),
+                "INFO",
+                &format!("Joined in-flight fetch of {}", endpoint),
+            );
+            return Retried {
+                result: retried.result,
+                attempts: 0,
+            };
+        }
+        retried
+    }
+
+    /// Fetch from the API within the rate limit, retrying per `RetryPolicy`,
+    /// and cache the response for the TTL `config` assigns to `endpoint`
+    /// Returns the response body, or the upstream error
+    pub(crate) async fn fetch_and_store(
+        env: &Env,
+        config: &CacheConfig,
+        endpoint: &str,
+        req: &Request,
+        cache: &WorkerCache,
+        cache_key: &str,
+    ) -> Retried<Result<String>> {
+        // Each attempt spends quota, so each one takes a token
+        let retried = RetryPolicy::from_env(env)
+            .run(
+                HttpMethod::Get,
+                || async {
+                    acquire_for_env(env).await?;
+                    Ok(Fetch::Request(create_api_request(env, endpoint, req)?)
+                        .send()
+                        .await?)
+                },
+                Delay::from,
+            )
+            .await;
+        let attempts = retried.attempts;
+        let result = match retried.result {
+            Ok(resp) => Self::store_response(cache, cache_key, config, endpoint, resp).await,
+            Err(e) => Err(e),
+        };
+        Retried { result, attempts }
+    }
+
+    /// Check and cache an upstream response, returning its body
+    /// 404s and empty results are cached for the shorter negative TTL
+    async fn store_response(
+        cache: &WorkerCache,
+        cache_key: &str,
+        config: &CacheConfig,
+        endpoint: &str,
+        mut resp: worker::Response,
+    ) -> Result<String> {
+        let negative_ttl = config.negative_ttl_for(endpoint);
 
         // Check HTTP status
         let status = resp.status_code();
-        if !(200..300).contains(&status) {
+        if status == 404 && negative_ttl > 0 {
             let text = resp.text().await.unwrap_or_default();
-            return Err(worker::Error::RustError(format!(
-                "API returned {}: {}",
-                status, text
+            put_negative_entry(cache, cache_key, status, &text, negative_ttl).await;
+            return Err(CourtListenerError::Api(ApiError::from_response(
+                status, &text, None,
             )));
         }
+        if !(200..300).contains(&status) {
+            return Err(upstream_error(&mut resp).await);
+        }
 
         let text = resp.text().await?;
 
-        // Parse with our types
-        let parsed: T = serde_json::from_str(&text).map_err(|e| {
-            // Truncate response text in error to avoid leaking sensitive data
-            let _truncated = if text.len() > 200 {
-                format!("{}...", &text[..200])
-            } else {
-                text.clone()
-            };
-            worker::Error::RustError(format!("Failed to parse JSON: {} (response truncated)", e))
-        })?;
+        // Only JSON is cached; callers parse it into their own types
+        if let Err(e) = serde_json::from_str::<serde::de::IgnoredAny>(&text) {
+            return Err(json_error(e));
+        }
 
         // Cache the response
-        set_cached_with_expiry(env, &cache_key, &text, cache_ttl).await;
+        let cache_ttl = if negative_ttl > 0 && is_empty_result(&text) {
+            negative_ttl
+        } else {
+            config.ttl_for(endpoint)
+        };
+        put_entry_with(
+            cache,
+            cache_key,
+            &text,
+            cache_ttl,
+            config.max_stale,
+            &config.storage,
+        )
+        .await;
 
-        Ok(parsed)
+        Ok(text)
     }
+}
 
-    /// Validate parsed data
-    fn validate_parsed<T: validator::Validate>(value: &T) -> worker::Result<()> {
-        value.validate().map_err(|errors| {
-            let error_msg = errors
-                .field_errors()
-                .iter()
-                .map(|(field, errors)| {
-                    let error_details: Vec<String> = errors
-                        .iter()
-                        .map(|e| format!("{:?}", e.code))
-                        .collect();
-                    format!("{}: {}", field, error_details.join(", "))
-                })
-                .collect::<Vec<String>>()
-                .join("; ");
-            
-            worker::Error::RustError(format!("Validation failed: {}", error_msg))
-        })
-    }
+/// Parse a response body with our types
+fn parse_json<T: serde::de::DeserializeOwned>(text: &str) -> Result<T> {
+    serde_json::from_str(text).map_err(json_error)
+}
+
+fn json_error(e: serde_json::Error) -> CourtListenerError {
+    // The response text is left out of the error to avoid leaking sensitive data
+    CourtListenerError::Json(format!("Failed to parse JSON: {} (response truncated)", e))
 }
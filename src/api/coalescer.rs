@@ -0,0 +1,116 @@
+//! Durable Object that coalesces upstream fetches across isolates
+//!
+//! With `CACHE_COALESCE=durable-object`, cache misses are sent to the
+//! `UpstreamCoalescer` instance named by their cache key. It fetches once for
+//! every concurrent caller, stores the response and returns the body. Bind it
+//! in wrangler.toml:
+//!
+//! ```toml
+//! [[durable_objects.bindings]]
+//! name = "COALESCER"
+//! class_name = "UpstreamCoalescer"
+//!
+//! [[migrations]]
+//! tag = "v1"
+//! new_classes = ["UpstreamCoalescer"]
+//! ```
+
+use crate::api::client::ApiClient;
+use crate::api::retry::Retried;
+use crate::cache::{CacheConfig, SingleFlight, WorkerCache};
+use crate::errors::{ApiError, CourtListenerError};
+use crate::utils::{error_response, with_attempts, UPSTREAM_ATTEMPTS_HEADER};
+use worker::*;
+
+/// Durable Object namespace binding
+const BINDING: &str = "COALESCER";
+/// Endpoint the coalesced request is for
+const ENDPOINT_HEADER: &str = "X-Coalesce-Endpoint";
+/// Cache key the response is stored under
+const CACHE_KEY_HEADER: &str = "X-Coalesce-Key";
+
+/// Shares upstream fetches for one cache key between isolates
+#[durable_object(fetch)]
+pub struct UpstreamCoalescer {
+    env: Env,
+    in_flight: SingleFlight<Retried<crate::errors::Result<String>>>,
+}
+
+impl DurableObject for UpstreamCoalescer {
+    fn new(_state: State, env: Env) -> Self {
+        Self {
+            env,
+            in_flight: SingleFlight::new(),
+        }
+    }
+
+    async fn fetch(&self, req: Request) -> Result<Response> {
+        let header = |name: &str| req.headers().get(name).ok().flatten();
+        let (Some(endpoint), Some(cache_key)) = (header(ENDPOINT_HEADER), header(CACHE_KEY_HEADER))
+        else {
+            return Response::error("Missing coalescing headers", 400);
+        };
+
+        let env = &self.env;
+        let (retried, joined) = self
+            .in_flight
+            .run(&cache_key, || async {
+                let config = CacheConfig::from_env(env);
+                let cache = WorkerCache::from_config(env, &config);
+                ApiClient::fetch_and_store(env, &config, &endpoint, &req, &cache, &cache_key).await
+            })
+            .await;
+
+        let response = match retried.result {
+            Ok(body) => Response::ok(body)?,
+            Err(e) => error_response(&e)?,
+        };
+        with_attempts(response, if joined { 0 } else { retried.attempts })
+    }
+}
+
+/// Fetch `endpoint` through the coalescer for `cache_key`
+/// None when the `COALESCER` binding is missing or unreachable, so the caller
+/// can fetch on its own
+pub(crate) async fn fetch_via_durable_object(
+    env: &Env,
+    endpoint: &str,
+    req: &Request,
+    cache_key: &str,
+) -> Option<Retried<crate::errors::Result<String>>> {
+    let namespace = env.durable_object(BINDING).ok()?;
+    let stub = namespace.id_from_name(cache_key).ok()?.get_stub().ok()?;
+
+    // The query travels in the URL, as create_api_request expects
+    let url = req.url().ok()?;
+    let target = match url.query() {
+        Some(query) => format!("https://coalescer.internal/?{}", query),
+        None => "https://coalescer.internal/".to_string(),
+    };
+    let mut coalesced = Request::new(&target, Method::Get).ok()?;
+    let headers = coalesced.headers_mut().ok()?;
+    headers.set(ENDPOINT_HEADER, endpoint).ok()?;
+    headers.set(CACHE_KEY_HEADER, cache_key).ok()?;
+    if let Ok(Some(accept)) = req.headers().get("Accept") {
+        headers.set("Accept", &accept).ok()?;
+    }
+
+    let mut resp = stub.fetch_with_request(coalesced).await.ok()?;
+    let header = |name: &str| resp.headers().get(name).ok().flatten();
+    let attempts = header(UPSTREAM_ATTEMPTS_HEADER)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    let retry_after = header("Retry-After");
+    let status = resp.status_code();
+    let body = resp.text().await.ok()?;
+    let result = if status == 200 {
+        Ok(body)
+    } else {
+        Err(CourtListenerError::Api(ApiError::from_response(
+            status,
+            &body,
+            retry_after.as_deref(),
+        )))
+    };
+    Some(Retried { result, attempts })
+}
@@ -0,0 +1,202 @@
+//! Transport-agnostic CourtListener client
+//!
+//! Works anywhere an `HttpTransport` is available: inside a Cloudflare Worker
+//! (`WorkerTransport`) or in native services (`ReqwestTransport`).
+
+use crate::api::rate_limit::{bucket_key, RateLimiter};
+use crate::api::retry::{Retried, RetryPolicy};
+use crate::api::transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport};
+use crate::config::get_api_base_url;
+use crate::errors::{ApiError, CourtListenerError, Result};
+
+/// CourtListener API client over any `HttpTransport`
+#[derive(Debug, Clone)]
+pub struct CourtListenerClient<T: HttpTransport> {
+    transport: T,
+    base_url: String,
+    token: Option<String>,
+    retry: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl<T: HttpTransport> CourtListenerClient<T> {
+    /// Create an unauthenticated client
+    /// Uses `get_api_base_url()`, so COURTLISTENER_API_BASE_URL is honoured
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            base_url: get_api_base_url(),
+            token: None,
+            retry: RetryPolicy::default(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Set the API token sent as `Authorization: Token <token>`
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Override the API base URL
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the retry policy (`RetryPolicy::default()` unless overridden)
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Retry policy applied to requests
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    /// Limit requests to the token's quota
+    /// Share one limiter (or its clones) between clients using the same token
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Requests left in the rate limit budget, if a limiter is configured
+    pub fn rate_limit_remaining(&self) -> Option<u32> {
+        self.rate_limiter
+            .as_ref()
+            .map(|limiter| limiter.remaining(&bucket_key(self.token.as_deref())))
+    }
+
+    /// API base URL requests are sent to
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Whether an API token is configured
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    /// Underlying transport
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Fetch and parse JSON from a CourtListener API endpoint
+    /// `endpoint` is relative to the base URL (e.g. "/courts/"), `query` is an
+    /// already-encoded query string without the leading '?'
+    pub async fn fetch_json<R: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query: Option<&str>,
+    ) -> Result<R> {
+        let url = self.endpoint_url(endpoint, query);
+        self.fetch_json_url(&url).await
+    }
+
+    /// Fetch and parse JSON, then validate it
+    /// Requires the type to implement `validator::Validate`
+    pub async fn fetch_json_validated<R>(&self, endpoint: &str, query: Option<&str>) -> Result<R>
+    where
+        R: serde::de::DeserializeOwned + validator::Validate,
+    {
+        let parsed: R = self.fetch_json(endpoint, query).await?;
+        parsed.validate()?;
+        Ok(parsed)
+    }
+
+    /// Fetch and parse JSON from an absolute URL
+    pub(crate) async fn fetch_json_url<R: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<R> {
+        let resp = self.send(self.request(HttpMethod::Get, url)).await.result?;
+
+        if !resp.is_success() {
+            return Err(CourtListenerError::Api(ApiError::from_response(
+                resp.status,
+                &resp.body,
+                resp.header("Retry-After"),
+            )));
+        }
+
+        serde_json::from_str(&resp.body).map_err(|e| {
+            CourtListenerError::Json(format!("Failed to parse JSON: {} (response truncated)", e))
+        })
+    }
+
+    /// Send a request through the transport, retrying per the retry policy
+    /// Every attempt takes a rate limit token; attempts the limiter refuses
+    /// are not sent or counted
+    pub async fn send(&self, request: HttpRequest) -> Retried<Result<HttpResponse>> {
+        self.retry
+            .run(
+                request.method,
+                || async {
+                    self.acquire_token().await?;
+                    self.transport.send(request.clone()).await
+                },
+                |wait| self.transport.sleep(wait),
+            )
+            .await
+    }
+
+    /// Take a rate limit token for one upstream attempt, if a limiter is set
+    async fn acquire_token(&self) -> Result<()> {
+        if let Some(limiter) = &self.rate_limiter {
+            let key = bucket_key(self.token.as_deref());
+            limiter.acquire(&key, |wait| self.transport.sleep(wait)).await?;
+        }
+        Ok(())
+    }
+
+    /// Build the absolute URL for an endpoint and optional query string
+    pub(crate) fn endpoint_url(&self, endpoint: &str, query: Option<&str>) -> String {
+        match query {
+            Some(q) if !q.is_empty() => format!("{}{}?{}", self.base_url, endpoint, q),
+            _ => format!("{}{}", self.base_url, endpoint),
+        }
+    }
+
+    /// Build an authenticated request with the standard headers
+    fn request(&self, method: HttpMethod, url: &str) -> HttpRequest {
+        let mut request = HttpRequest::new(method, url)
+            .header("Accept", "application/json")
+            .header(
+                "User-Agent",
+                format!("courtlistener-worker/{}", env!("CARGO_PKG_VERSION")),
+            );
+        if let Some(token) = &self.token {
+            request = request.header("Authorization", format!("Token {}", token));
+        }
+        request
+    }
+}
+
+#[cfg(feature = "worker")]
+impl CourtListenerClient<crate::api::transport::WorkerTransport> {
+    /// Create a client for use inside a worker
+    /// Reads the COURTLISTENER_API_TOKEN secret if it is bound
+    pub fn from_env(env: &worker::Env) -> Self {
+        let client = Self::new(crate::api::transport::WorkerTransport);
+        match env.secret("COURTLISTENER_API_TOKEN") {
+            Ok(token) => client.with_token(token.to_string()),
+            Err(_) => client,
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+impl CourtListenerClient<crate::api::transport::ReqwestTransport> {
+    /// Create a native client
+    /// Reads the COURTLISTENER_API_TOKEN environment variable if it is set
+    pub fn native() -> Self {
+        let client = Self::new(crate::api::transport::ReqwestTransport::new());
+        match std::env::var("COURTLISTENER_API_TOKEN") {
+            Ok(token) if !token.is_empty() => client.with_token(token),
+            _ => client,
+        }
+    }
+}
@@ -1,6 +1,38 @@
 //! API client module for fetching data from CourtListener API
 
+pub mod audio;
+pub mod http_client;
+pub mod pagination;
+pub mod rate_limit;
+pub mod refs;
+pub mod resources;
+pub mod retry;
+pub mod transport;
+
+#[cfg(feature = "worker")]
 pub mod client;
+#[cfg(feature = "worker")]
+pub mod coalescer;
+#[cfg(feature = "worker")]
+pub mod rate_limiter;
+#[cfg(feature = "worker")]
 pub mod request;
 
-pub use client::ApiClient;
+pub use http_client::CourtListenerClient;
+pub use pagination::PaginationOptions;
+pub use rate_limit::{RateLimitConfig, RateLimitMode, RateLimiter};
+pub use resources::{Resource, Search};
+pub use retry::{Retried, RetryPolicy, RetryableResponse};
+pub use transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport};
+
+#[cfg(feature = "worker")]
+pub use client::{ApiClient, Fetched};
+#[cfg(feature = "worker")]
+pub use coalescer::UpstreamCoalescer;
+#[cfg(feature = "worker")]
+pub use rate_limiter::RateLimiterObject;
+#[cfg(feature = "worker")]
+pub use transport::WorkerTransport;
+
+#[cfg(feature = "native")]
+pub use transport::ReqwestTransport;
@@ -0,0 +1,242 @@
+//! Auto-pagination over `PaginatedResponse` results
+//!
+//! The streams follow the `next` link of each page until it is null or a
+//! configured bound is reached. Only the query string of `next` is used and it
+//! is applied to the original endpoint, so page-number and cursor pagination
+//! both work and requests keep going to the client's base URL (e.g. a proxy).
+
+use crate::api::http_client::CourtListenerClient;
+use crate::api::resources::Resource;
+use crate::api::transport::HttpTransport;
+use crate::errors::{CourtListenerError, Result};
+use crate::query::ToQuery;
+use crate::types::common::PaginatedResponse;
+use futures_util::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+/// Bounds for paginated streams (unbounded by default)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PaginationOptions {
+    /// Stop after yielding this many items
+    pub max_items: Option<usize>,
+    /// Stop after fetching this many pages
+    pub max_pages: Option<usize>,
+}
+
+impl PaginationOptions {
+    /// Unbounded pagination
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop after yielding `max_items` items
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Stop after fetching `max_pages` pages
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+}
+
+/// Cursor over the pages of a list endpoint
+struct PageCursor {
+    endpoint: String,
+    /// Query string of the next page to fetch (None once exhausted)
+    next_query: Option<String>,
+    /// Query validation error, reported as the only stream item
+    error: Option<CourtListenerError>,
+    pages_fetched: usize,
+    max_pages: Option<usize>,
+}
+
+impl PageCursor {
+    fn new(endpoint: &str, query: Result<String>, options: PaginationOptions) -> Self {
+        let (next_query, error) = match query {
+            Ok(query) => (Some(query), None),
+            Err(e) => (None, Some(e)),
+        };
+        Self {
+            endpoint: endpoint.to_string(),
+            next_query,
+            error,
+            pages_fetched: 0,
+            max_pages: options.max_pages,
+        }
+    }
+
+    /// Fetch the next page, or None when there are no more pages to fetch
+    async fn next_page<T, R>(
+        &mut self,
+        client: &CourtListenerClient<T>,
+    ) -> Option<Result<PaginatedResponse<R>>>
+    where
+        T: HttpTransport,
+        R: serde::de::DeserializeOwned,
+    {
+        if let Some(e) = self.error.take() {
+            return Some(Err(e));
+        }
+        if self.max_pages.is_some_and(|max| self.pages_fetched >= max) {
+            return None;
+        }
+        let query = self.next_query.take()?;
+        let url = client.endpoint_url(&self.endpoint, Some(&query));
+
+        match client.fetch_json_url::<PaginatedResponse<R>>(&url).await {
+            Ok(page) => {
+                self.pages_fetched += 1;
+                self.next_query = page.next.as_deref().map(query_of);
+                Some(Ok(page))
+            }
+            // Stop after the first error
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Query string of a URL (empty if it has none)
+fn query_of(url: &str) -> String {
+    url.split_once('?')
+        .map(|(_, q)| q.split('#').next().unwrap_or_default().to_string())
+        .unwrap_or_default()
+}
+
+impl<T: HttpTransport> CourtListenerClient<T> {
+    /// Stream every page of a list endpoint
+    pub fn pages<'a, R>(
+        &'a self,
+        endpoint: &str,
+        query: Option<&str>,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<PaginatedResponse<R>>> + 'a
+    where
+        R: serde::de::DeserializeOwned + 'a,
+    {
+        let query = Ok(query.unwrap_or_default().to_string());
+        self.pages_checked(endpoint, query, options)
+    }
+
+    /// Stream every item of a list endpoint, fetching pages as needed
+    pub fn paginate<'a, R>(
+        &'a self,
+        endpoint: &str,
+        query: Option<&str>,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<R>> + 'a
+    where
+        R: serde::de::DeserializeOwned + 'a,
+    {
+        let query = Ok(query.unwrap_or_default().to_string());
+        self.paginate_checked(endpoint, query, options)
+    }
+
+    /// Page stream over a query that may have failed validation
+    fn pages_checked<'a, R>(
+        &'a self,
+        endpoint: &str,
+        query: Result<String>,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<PaginatedResponse<R>>> + 'a
+    where
+        R: serde::de::DeserializeOwned + 'a,
+    {
+        let cursor = PageCursor::new(endpoint, query, options);
+        stream::unfold(cursor, move |mut cursor| async move {
+            let page = cursor.next_page(self).await?;
+            Some((page, cursor))
+        })
+    }
+
+    /// Item stream over a query that may have failed validation
+    fn paginate_checked<'a, R>(
+        &'a self,
+        endpoint: &str,
+        query: Result<String>,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<R>> + 'a
+    where
+        R: serde::de::DeserializeOwned + 'a,
+    {
+        struct State<R> {
+            cursor: PageCursor,
+            buffer: VecDeque<R>,
+            remaining: Option<usize>,
+        }
+
+        let state = State {
+            cursor: PageCursor::new(endpoint, query, options),
+            buffer: VecDeque::new(),
+            remaining: options.max_items,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            if state.remaining == Some(0) {
+                return None;
+            }
+            while state.buffer.is_empty() {
+                match state.cursor.next_page::<T, R>(self).await? {
+                    Ok(page) => state.buffer.extend(page.results),
+                    Err(e) => {
+                        state.remaining = Some(0);
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+            let item = state.buffer.pop_front()?;
+            state.remaining = state.remaining.map(|n| n - 1);
+            Some((Ok(item), state))
+        })
+    }
+}
+
+/// Validate and serialize a query for a paginated stream
+fn checked_query<Q: ToQuery + ?Sized>(query: &Q) -> Result<String> {
+    query.validate_query().map(|_| query.to_query())
+}
+
+impl<'a, T, Item, Id> Resource<'a, T, Item, Id>
+where
+    T: HttpTransport,
+    Item: serde::de::DeserializeOwned + 'a,
+    Id: Display,
+{
+    /// Stream every item of this resource
+    pub fn stream(&self, options: PaginationOptions) -> impl Stream<Item = Result<Item>> + 'a {
+        self.client().paginate(self.path(), None, options)
+    }
+
+    /// Stream every item matching a query
+    /// An invalid query is reported as the only item of the stream
+    pub fn stream_with<Q: ToQuery + ?Sized>(
+        &self,
+        query: &Q,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<Item>> + 'a {
+        self.client()
+            .paginate_checked(self.path(), checked_query(query), options)
+    }
+
+    /// Stream every page of this resource
+    pub fn pages(
+        &self,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<PaginatedResponse<Item>>> + 'a {
+        self.client().pages(self.path(), None, options)
+    }
+
+    /// Stream every page matching a query
+    /// An invalid query is reported as the only item of the stream
+    pub fn pages_with<Q: ToQuery + ?Sized>(
+        &self,
+        query: &Q,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<PaginatedResponse<Item>>> + 'a {
+        self.client()
+            .pages_checked(self.path(), checked_query(query), options)
+    }
+}
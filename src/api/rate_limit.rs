@@ -0,0 +1,416 @@
+//! Client-side rate limiting against the CourtListener API quota
+//!
+//! CourtListener throttles each API token per hour. A token bucket per token
+//! spends one token per upstream request and refills at the hourly quota, so
+//! bursts are allowed up to `capacity` while the sustained rate stays within
+//! the quota. When the bucket is empty a call is either queued until a token
+//! is available or rejected with `CourtListenerError::RateLimited`.
+//!
+//! - `RateLimiter`: in-memory buckets, shared by clones (native or per isolate)
+//! - `DurableRateLimiter`: buckets in the `RateLimiterObject` Durable Object,
+//!   shared exactly across isolates (worker feature)
+
+use crate::errors::{CourtListenerError, Result};
+use crate::time::now_millis;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Bucket key used when no API token is configured
+const ANONYMOUS_KEY: &str = "anonymous";
+
+/// What to do with a call when the bucket is empty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Fail immediately with `CourtListenerError::RateLimited`
+    Reject,
+    /// Wait for a token, failing if that would take longer than `max_wait`
+    Queue { max_wait: Duration },
+}
+
+impl RateLimitMode {
+    /// Mode name as reported by `/health`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Reject => "reject",
+            Self::Queue { .. } => "queue",
+        }
+    }
+}
+
+/// Token bucket settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Maximum burst size
+    pub capacity: u32,
+    /// Tokens added per hour (the upstream quota)
+    pub refill_per_hour: u32,
+    pub mode: RateLimitMode,
+}
+
+impl Default for RateLimitConfig {
+    /// CourtListener's default authenticated quota of 5,000 requests per hour
+    fn default() -> Self {
+        Self {
+            capacity: 100,
+            refill_per_hour: 5000,
+            mode: RateLimitMode::Reject,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// Default config: burst of 100, 5,000 per hour, reject when empty
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum burst size (at least 1)
+    pub fn capacity(mut self, capacity: u32) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Set the hourly refill rate (at least 1)
+    pub fn refill_per_hour(mut self, refill_per_hour: u32) -> Self {
+        self.refill_per_hour = refill_per_hour.max(1);
+        self
+    }
+
+    /// Set the empty-bucket behaviour
+    pub fn mode(mut self, mode: RateLimitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Read the config from worker vars; None when rate limiting is disabled
+    /// - `RATE_LIMIT_PER_HOUR`: hourly quota (default 5000, 0 disables)
+    /// - `RATE_LIMIT_BURST`: bucket capacity (default 100)
+    /// - `RATE_LIMIT_MODE`: "reject" (default) or "queue"
+    /// - `RATE_LIMIT_MAX_WAIT_MS`: longest queue wait (default 10000)
+    #[cfg(feature = "worker")]
+    pub fn from_env(env: &worker::Env) -> Option<Self> {
+        let var = |name: &str| env.var(name).ok().map(|v| v.to_string());
+        let number = |name: &str| var(name).and_then(|v| v.trim().parse::<u64>().ok());
+
+        let mut config = Self::default();
+        match number("RATE_LIMIT_PER_HOUR") {
+            Some(0) => return None,
+            Some(n) => config = config.refill_per_hour(n.min(u32::MAX as u64) as u32),
+            None => {}
+        }
+        if let Some(n) = number("RATE_LIMIT_BURST") {
+            config = config.capacity(n.min(u32::MAX as u64) as u32);
+        }
+        if var("RATE_LIMIT_MODE").is_some_and(|m| m.trim().eq_ignore_ascii_case("queue")) {
+            let max_wait = number("RATE_LIMIT_MAX_WAIT_MS").unwrap_or(10_000);
+            config = config.mode(RateLimitMode::Queue {
+                max_wait: Duration::from_millis(max_wait),
+            });
+        }
+        Some(config)
+    }
+
+    fn refill_per_ms(&self) -> f64 {
+        self.refill_per_hour as f64 / 3_600_000.0
+    }
+}
+
+/// State of one token bucket
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TokenBucket {
+    pub tokens: f64,
+    /// Time of the last refill (ms since the Unix epoch)
+    pub updated_ms: u64,
+}
+
+impl TokenBucket {
+    /// A full bucket
+    pub fn full(config: &RateLimitConfig, now_ms: u64) -> Self {
+        Self {
+            tokens: config.capacity as f64,
+            updated_ms: now_ms,
+        }
+    }
+
+    fn refill(&mut self, config: &RateLimitConfig, now_ms: u64) {
+        let elapsed = now_ms.saturating_sub(self.updated_ms) as f64;
+        self.tokens = (self.tokens + elapsed * config.refill_per_ms()).min(config.capacity as f64);
+        self.updated_ms = now_ms.max(self.updated_ms);
+    }
+
+    /// Take a token, returning the tokens left or how long until one is available
+    pub fn try_take(
+        &mut self,
+        config: &RateLimitConfig,
+        now_ms: u64,
+    ) -> std::result::Result<u32, Duration> {
+        self.refill(config, now_ms);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(self.tokens as u32)
+        } else {
+            let ms = ((1.0 - self.tokens) / config.refill_per_ms()).ceil();
+            Err(Duration::from_millis(ms as u64))
+        }
+    }
+
+    /// Whole tokens available at `now_ms`
+    pub fn remaining(&self, config: &RateLimitConfig, now_ms: u64) -> u32 {
+        let mut bucket = *self;
+        bucket.refill(config, now_ms);
+        bucket.tokens as u32
+    }
+}
+
+/// Bucket key for an API token
+/// Tokens are hashed so they never appear in storage keys or logs
+pub fn bucket_key(token: Option<&str>) -> String {
+    use sha2::{Digest, Sha256};
+    match token {
+        Some(token) if !token.is_empty() => {
+            let digest = Sha256::digest(token.as_bytes());
+            digest[..16].iter().map(|b| format!("{:02x}", b)).collect()
+        }
+        _ => ANONYMOUS_KEY.to_string(),
+    }
+}
+
+/// Take a token with `take`, waiting with `sleep` in queue mode
+/// Returns the tokens left after the call
+pub(crate) async fn acquire_with<T, TFut, S, SFut>(
+    config: &RateLimitConfig,
+    mut take: T,
+    sleep: S,
+) -> Result<u32>
+where
+    T: FnMut() -> TFut,
+    TFut: Future<Output = Result<std::result::Result<u32, Duration>>>,
+    S: Fn(Duration) -> SFut,
+    SFut: Future<Output = ()>,
+{
+    let mut waited = Duration::ZERO;
+    loop {
+        let wait = match take().await? {
+            Ok(remaining) => return Ok(remaining),
+            Err(wait) => wait,
+        };
+        match config.mode {
+            RateLimitMode::Queue { max_wait } if waited + wait <= max_wait => {
+                sleep(wait).await;
+                waited += wait;
+            }
+            _ => {
+                return Err(CourtListenerError::RateLimited(
+                    wait.as_millis().div_ceil(1000) as u64,
+                ))
+            }
+        }
+    }
+}
+
+/// In-memory token buckets keyed by API token
+/// Clones share the same buckets
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter with empty state
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// Take a token for `key` at `now_ms`
+    pub fn try_take_at(&self, key: &str, now_ms: u64) -> std::result::Result<u32, Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        buckets
+            .entry(key.to_string())
+            .or_insert_with(|| TokenBucket::full(&self.config, now_ms))
+            .try_take(&self.config, now_ms)
+    }
+
+    /// Take a token for `key`, waiting with `sleep` in queue mode
+    pub async fn acquire<S, SFut>(&self, key: &str, sleep: S) -> Result<u32>
+    where
+        S: Fn(Duration) -> SFut,
+        SFut: Future<Output = ()>,
+    {
+        acquire_with(
+            &self.config,
+            || async { Ok(self.try_take_at(key, now_millis())) },
+            sleep,
+        )
+        .await
+    }
+
+    /// Tokens currently available for `key`
+    pub fn remaining(&self, key: &str) -> u32 {
+        let buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        buckets
+            .get(key)
+            .map(|bucket| bucket.remaining(&self.config, now_millis()))
+            .unwrap_or(self.config.capacity)
+    }
+}
+
+/// Reply of the `RateLimiterObject` Durable Object
+#[cfg(feature = "worker")]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct BucketReply {
+    /// Tokens left in the bucket
+    pub remaining: u32,
+    /// Milliseconds until a token is available, when none could be taken
+    pub wait_ms: Option<u64>,
+}
+
+/// Token buckets held by the `RateLimiterObject` Durable Object, one instance
+/// per bucket key, so every isolate spends from the same exact budget
+#[cfg(feature = "worker")]
+pub struct DurableRateLimiter {
+    namespace: worker::ObjectNamespace,
+    config: RateLimitConfig,
+}
+
+#[cfg(feature = "worker")]
+impl DurableRateLimiter {
+    pub fn new(namespace: worker::ObjectNamespace, config: RateLimitConfig) -> Self {
+        Self { namespace, config }
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    async fn call(&self, key: &str, path: &str) -> Result<BucketReply> {
+        let stub = self.namespace.id_from_name(key)?.get_stub()?;
+        let url = format!("{}{}", crate::api::rate_limiter::BASE_URL, path);
+        let mut resp = stub.fetch_with_str(&url).await?;
+        if resp.status_code() != 200 {
+            return Err(CourtListenerError::Http(format!(
+                "Rate limiter returned {}",
+                resp.status_code()
+            )));
+        }
+        Ok(resp.json().await?)
+    }
+
+    async fn try_take(&self, key: &str) -> Result<std::result::Result<u32, Duration>> {
+        let reply = self.call(key, "/take").await?;
+        Ok(match reply.wait_ms {
+            Some(ms) => Err(Duration::from_millis(ms)),
+            None => Ok(reply.remaining),
+        })
+    }
+
+    /// Take a token for `key`, waiting in queue mode
+    pub async fn acquire(&self, key: &str) -> Result<u32> {
+        acquire_with(&self.config, || self.try_take(key), worker::Delay::from).await
+    }
+
+    /// Tokens currently available for `key`
+    pub async fn remaining(&self, key: &str) -> Result<u32> {
+        Ok(self.call(key, "/remaining").await?.remaining)
+    }
+}
+
+/// Rate limiter for worker requests, configured from `Env`
+/// Uses the `RATE_LIMITER` Durable Object when bound, otherwise per-isolate
+/// memory. KV is not used: it takes about one write per second per key and
+/// serves reads up to 60 seconds stale, too weak for a shared counter
+#[cfg(feature = "worker")]
+pub enum WorkerRateLimiter {
+    DurableObject(DurableRateLimiter),
+    Memory(RateLimiter),
+}
+
+#[cfg(feature = "worker")]
+impl WorkerRateLimiter {
+    /// Build the limiter for this request; None when rate limiting is disabled
+    pub fn from_env(env: &worker::Env) -> Option<Self> {
+        thread_local! {
+            static ISOLATE_LIMITER: std::cell::RefCell<Option<RateLimiter>> =
+                const { std::cell::RefCell::new(None) };
+        }
+
+        let config = RateLimitConfig::from_env(env)?;
+        if let Ok(namespace) = env.durable_object(crate::api::rate_limiter::BINDING) {
+            return Some(Self::DurableObject(DurableRateLimiter::new(
+                namespace, config,
+            )));
+        }
+        let limiter = ISOLATE_LIMITER.with(|cell| {
+            let mut cell = cell.borrow_mut();
+            match cell.as_ref() {
+                Some(limiter) if limiter.config() == &config => limiter.clone(),
+                _ => cell.insert(RateLimiter::new(config)).clone(),
+            }
+        });
+        Some(Self::Memory(limiter))
+    }
+
+    /// Bucket key for the configured API token
+    pub fn key_for(env: &worker::Env) -> String {
+        let token = env
+            .secret("COURTLISTENER_API_TOKEN")
+            .ok()
+            .map(|t| t.to_string());
+        bucket_key(token.as_deref())
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        match self {
+            Self::DurableObject(limiter) => limiter.config(),
+            Self::Memory(limiter) => limiter.config(),
+        }
+    }
+
+    /// Storage backend name as reported by `/health`
+    pub fn backend(&self) -> &'static str {
+        match self {
+            Self::DurableObject(_) => "durable-object",
+            Self::Memory(_) => "memory",
+        }
+    }
+
+    /// Take a token for `key`, waiting in queue mode
+    pub async fn acquire(&self, key: &str) -> Result<u32> {
+        match self {
+            Self::DurableObject(limiter) => limiter.acquire(key).await,
+            Self::Memory(limiter) => limiter.acquire(key, worker::Delay::from).await,
+        }
+    }
+
+    /// Tokens currently available for `key`
+    pub async fn remaining(&self, key: &str) -> Result<u32> {
+        match self {
+            Self::DurableObject(limiter) => limiter.remaining(key).await,
+            Self::Memory(limiter) => Ok(limiter.remaining(key)),
+        }
+    }
+}
+
+/// Take a token for an upstream request from this worker, if rate limiting is enabled
+/// Only an exhausted budget is an error; limiter failures let the request through
+#[cfg(feature = "worker")]
+pub async fn acquire_for_env(env: &worker::Env) -> Result<()> {
+    if let Some(limiter) = WorkerRateLimiter::from_env(env) {
+        match limiter.acquire(&WorkerRateLimiter::key_for(env)).await {
+            Ok(_) => {}
+            Err(e @ CourtListenerError::RateLimited(_)) => return Err(e),
+            Err(e) => worker::console_log!("Rate limiter failed, allowing request: {}", e),
+        }
+    }
+    Ok(())
+}
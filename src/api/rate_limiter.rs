@@ -0,0 +1,86 @@
+//! Durable Object that holds the shared rate limit buckets
+//!
+//! Each bucket key (a hash of the API token) gets its own `RateLimiterObject`
+//! instance. A Durable Object handles its requests one at a time, so every
+//! isolate spends from the same bucket without the lost updates and stale
+//! reads a KV counter would have. Bind it in wrangler.toml:
+//!
+//! ```toml
+//! [[durable_objects.bindings]]
+//! name = "RATE_LIMITER"
+//! class_name = "RateLimiterObject"
+//!
+//! [[migrations]]
+//! tag = "v2"
+//! new_classes = ["RateLimiterObject"]
+//! ```
+
+use crate::api::rate_limit::{BucketReply, RateLimitConfig, TokenBucket};
+use crate::time::now_millis;
+use std::cell::Cell;
+use worker::*;
+
+/// Durable Object namespace binding
+pub(crate) const BINDING: &str = "RATE_LIMITER";
+/// Base URL of requests to the object; only the path is used
+pub(crate) const BASE_URL: &str = "https://rate-limiter.internal";
+/// Storage key of the bucket, so it survives the object being evicted
+const BUCKET_KEY: &str = "bucket";
+
+/// Token bucket for one API token, shared by every isolate
+/// `/take` spends a token; `/remaining` only reports the budget
+#[durable_object(fetch)]
+pub struct RateLimiterObject {
+    state: State,
+    env: Env,
+    bucket: Cell<Option<TokenBucket>>,
+}
+
+impl DurableObject for RateLimiterObject {
+    fn new(state: State, env: Env) -> Self {
+        Self {
+            state,
+            env,
+            bucket: Cell::new(None),
+        }
+    }
+
+    async fn fetch(&self, req: Request) -> Result<Response> {
+        let Some(config) = RateLimitConfig::from_env(&self.env) else {
+            return Response::error("Rate limiting is disabled", 404);
+        };
+        let now_ms = now_millis();
+        let mut bucket = match self.bucket.get() {
+            Some(bucket) => bucket,
+            None => self
+                .state
+                .storage()
+                .get(BUCKET_KEY)
+                .await?
+                .unwrap_or_else(|| TokenBucket::full(&config, now_ms)),
+        };
+
+        let reply = match req.path().as_str() {
+            "/take" => match bucket.try_take(&config, now_ms) {
+                Ok(remaining) => {
+                    self.bucket.set(Some(bucket));
+                    self.state.storage().put(BUCKET_KEY, bucket).await?;
+                    BucketReply {
+                        remaining,
+                        wait_ms: None,
+                    }
+                }
+                Err(wait) => BucketReply {
+                    remaining: 0,
+                    wait_ms: Some(wait.as_millis() as u64),
+                },
+            },
+            "/remaining" => BucketReply {
+                remaining: bucket.remaining(&config, now_ms),
+                wait_ms: None,
+            },
+            _ => return Response::error("Not found", 404),
+        };
+        Response::from_json(&reply)
+    }
+}
@@ -0,0 +1,74 @@
+//! Resolving `Ref<T>` references through the client
+//!
+//! ```ignore
+//! let docket = audio.docket.as_ref().unwrap().resolve(&client).await?;
+//! let judges = client.resolve_refs(&audio.judges.unwrap_or_default(), Some(50)).await?;
+//! ```
+
+use crate::api::http_client::CourtListenerClient;
+use crate::api::pagination::PaginationOptions;
+use crate::api::resources::Resource;
+use crate::api::transport::HttpTransport;
+use crate::errors::Result;
+use crate::types::reference::{ApiResource, Ref};
+use futures_util::TryStreamExt;
+use std::collections::{HashMap, HashSet};
+
+impl<R: ApiResource> Ref<R> {
+    /// Fetch the referenced object
+    pub async fn resolve<T: HttpTransport>(&self, client: &CourtListenerClient<T>) -> Result<R> {
+        client.fetch_json(&self.endpoint(), None).await
+    }
+}
+
+impl<T: HttpTransport> CourtListenerClient<T> {
+    /// The list/detail endpoint of any `ApiResource` type
+    pub fn resource<R: ApiResource>(&self) -> Resource<'_, T, R, R::Id> {
+        Resource::new(self, R::PATH)
+    }
+
+    /// Resolve references to objects, keyed by id
+    /// With `batch_size`, ids are fetched `batch_size` at a time through the
+    /// list endpoint (`id__in`); otherwise each id is fetched on its own.
+    /// Duplicate references are fetched once; ids a batch does not return are
+    /// missing from the map
+    pub async fn resolve_refs<R: ApiResource>(
+        &self,
+        refs: &[Ref<R>],
+        batch_size: Option<usize>,
+    ) -> Result<HashMap<R::Id, R>> {
+        let mut seen = HashSet::new();
+        let ids: Vec<&R::Id> = refs
+            .iter()
+            .map(Ref::id)
+            .filter(|id| seen.insert(*id))
+            .collect();
+
+        let mut resolved = HashMap::with_capacity(ids.len());
+        match batch_size {
+            Some(size) => {
+                for chunk in ids.chunks(size.max(1)) {
+                    let id_in = chunk
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    let query = [("id__in", id_in), ("page_size", chunk.len().to_string())];
+                    let objects: Vec<R> = self
+                        .resource::<R>()
+                        .stream_with(&query, PaginationOptions::new())
+                        .try_collect()
+                        .await?;
+                    resolved.extend(objects.into_iter().map(|object| (object.id(), object)));
+                }
+            }
+            None => {
+                for id in ids {
+                    let object = self.resource::<R>().get(id.clone()).await?;
+                    resolved.insert(id.clone(), object);
+                }
+            }
+        }
+        Ok(resolved)
+    }
+}
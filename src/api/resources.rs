@@ -0,0 +1,244 @@
+//! Resource-oriented access to CourtListener endpoints
+//!
+//! Each accessor on `CourtListenerClient` pairs an endpoint path with its
+//! response type, so callers never have to match them up by hand:
+//!
+//! ```ignore
+//! let courts = client.courts().list().await?;
+//! let scotus = client.courts().get("scotus").await?;
+//! let docket = client.dockets().get(4214664).await?;
+//! ```
+
+use crate::api::http_client::CourtListenerClient;
+use crate::api::transport::HttpTransport;
+use crate::errors::Result;
+use crate::query::{encode, search_type_of, ToQuery};
+use crate::types::*;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// A list/detail endpoint of the CourtListener API
+/// `Item` is the type of list results and detail responses, `Id` the type of its primary key
+pub struct Resource<'a, T: HttpTransport, Item, Id> {
+    client: &'a CourtListenerClient<T>,
+    path: &'static str,
+    _marker: PhantomData<fn() -> (Item, Id)>,
+}
+
+impl<'a, T, Item, Id> Resource<'a, T, Item, Id>
+where
+    T: HttpTransport,
+    Item: serde::de::DeserializeOwned,
+    Id: Display,
+{
+    pub(crate) fn new(client: &'a CourtListenerClient<T>, path: &'static str) -> Self {
+        Self {
+            client,
+            path,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Client this resource sends requests through
+    pub(crate) fn client(&self) -> &'a CourtListenerClient<T> {
+        self.client
+    }
+
+    /// Endpoint path (e.g. "/courts/")
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Fetch the first page of results
+    pub async fn list(&self) -> Result<PaginatedResponse<Item>> {
+        self.client.fetch_json(self.path, None).await
+    }
+
+    /// Fetch the first page of results matching a query (filters, ordering, fields, page size)
+    pub async fn list_with<Q: ToQuery + ?Sized>(
+        &self,
+        query: &Q,
+    ) -> Result<PaginatedResponse<Item>> {
+        query.validate_query()?;
+        let query = query.to_query();
+        self.client.fetch_json(self.path, Some(&query)).await
+    }
+
+    /// Fetch a single object by id
+    pub async fn get(&self, id: impl Into<Id>) -> Result<Item> {
+        let endpoint = format!("{}{}/", self.path, encode(&id.into().to_string()));
+        self.client.fetch_json(&endpoint, None).await
+    }
+}
+
+/// The search endpoint, which is query-only and has no detail view
+pub struct Search<'a, T: HttpTransport> {
+    client: &'a CourtListenerClient<T>,
+}
+
+impl<T: HttpTransport> Search<'_, T> {
+    /// Run a search from a `SearchQuery` or raw parameters
+    /// (e.g. `[("q", "constitution"), ("type", "o")]`)
+    /// Results are typed by the query's `type=` parameter
+    pub async fn query<Q: ToQuery + ?Sized>(&self, query: &Q) -> Result<SearchResponse> {
+        query.validate_query()?;
+        let query = query.to_query();
+        let search_type = search_type_of(&query)?;
+        let value: serde_json::Value = self.client.fetch_json("/search/", Some(&query)).await?;
+        Ok(SearchResponse::from_value(search_type, value)?)
+    }
+}
+
+impl<T: HttpTransport> CourtListenerClient<T> {
+    /// Courts (`/courts/`)
+    pub fn courts(&self) -> Resource<'_, T, ApiCourt, CourtId> {
+        Resource::new(self, "/courts/")
+    }
+
+    /// Dockets (`/dockets/`)
+    pub fn dockets(&self) -> Resource<'_, T, Docket, DocketId> {
+        Resource::new(self, "/dockets/")
+    }
+
+    /// Docket entries, with their RECAP documents nested (`/docket-entries/`)
+    pub fn docket_entries(&self) -> Resource<'_, T, DocketEntry, DocketEntryId> {
+        Resource::new(self, "/docket-entries/")
+    }
+
+    /// RECAP documents (`/recap-documents/`)
+    pub fn recap_documents(&self) -> Resource<'_, T, RecapDocument, RecapDocumentId> {
+        Resource::new(self, "/recap-documents/")
+    }
+
+    /// Parties to RECAP dockets (`/parties/`)
+    pub fn parties(&self) -> Resource<'_, T, Party, PartyId> {
+        Resource::new(self, "/parties/")
+    }
+
+    /// Attorneys in RECAP dockets (`/attorneys/`)
+    pub fn attorneys(&self) -> Resource<'_, T, Attorney, AttorneyId> {
+        Resource::new(self, "/attorneys/")
+    }
+
+    /// Lower court information for appellate dockets (`/originating-court-information/`)
+    pub fn originating_court_information(
+        &self,
+    ) -> Resource<'_, T, OriginatingCourtInformation, u32> {
+        Resource::new(self, "/originating-court-information/")
+    }
+
+    /// Federal Judicial Center Integrated Database records (`/fjc-integrated-database/`)
+    pub fn fjc_integrated_database(&self) -> Resource<'_, T, FjcIntegratedDatabase, u32> {
+        Resource::new(self, "/fjc-integrated-database/")
+    }
+
+    /// Opinion clusters (`/clusters/`)
+    pub fn clusters(&self) -> Resource<'_, T, ApiOpinionCluster, ClusterId> {
+        Resource::new(self, "/clusters/")
+    }
+
+    /// Opinions (`/opinions/`)
+    pub fn opinions(&self) -> Resource<'_, T, ApiOpinion, OpinionId> {
+        Resource::new(self, "/opinions/")
+    }
+
+    /// People: judges and other court personnel (`/people/`)
+    pub fn people(&self) -> Resource<'_, T, ApiPerson, PersonId> {
+        Resource::new(self, "/people/")
+    }
+
+    /// Positions held by people, with retention events nested (`/positions/`)
+    pub fn positions(&self) -> Resource<'_, T, Position, PositionId> {
+        Resource::new(self, "/positions/")
+    }
+
+    /// Retention events for positions (`/retention-events/`)
+    pub fn retention_events(&self) -> Resource<'_, T, RetentionEvent, u32> {
+        Resource::new(self, "/retention-events/")
+    }
+
+    /// Degrees people earned, with the school nested (`/educations/`)
+    pub fn educations(&self) -> Resource<'_, T, Education, u32> {
+        Resource::new(self, "/educations/")
+    }
+
+    /// Schools (`/schools/`)
+    pub fn schools(&self) -> Resource<'_, T, School, SchoolId> {
+        Resource::new(self, "/schools/")
+    }
+
+    /// Political party affiliations (`/political-affiliations/`)
+    pub fn political_affiliations(&self) -> Resource<'_, T, PoliticalAffiliation, u32> {
+        Resource::new(self, "/political-affiliations/")
+    }
+
+    /// American Bar Association ratings (`/aba-ratings/`)
+    pub fn aba_ratings(&self) -> Resource<'_, T, AbaRating, u32> {
+        Resource::new(self, "/aba-ratings/")
+    }
+
+    /// Sources of biographical data (`/sources/`)
+    pub fn sources(&self) -> Resource<'_, T, Source, u32> {
+        Resource::new(self, "/sources/")
+    }
+
+    /// Financial disclosure reports, with their parts nested (`/financial-disclosures/`)
+    pub fn financial_disclosures(&self) -> Resource<'_, T, FinancialDisclosure, u32> {
+        Resource::new(self, "/financial-disclosures/")
+    }
+
+    /// Investments and trusts listed on disclosures (`/investments/`)
+    pub fn investments(&self) -> Resource<'_, T, Investment, u32> {
+        Resource::new(self, "/investments/")
+    }
+
+    /// Outside positions listed on disclosures (`/disclosure-positions/`)
+    pub fn disclosure_positions(&self) -> Resource<'_, T, DisclosurePosition, u32> {
+        Resource::new(self, "/disclosure-positions/")
+    }
+
+    /// Agreements with employers listed on disclosures (`/agreements/`)
+    pub fn agreements(&self) -> Resource<'_, T, Agreement, u32> {
+        Resource::new(self, "/agreements/")
+    }
+
+    /// Non-investment incomes listed on disclosures (`/non-investment-incomes/`)
+    pub fn non_investment_incomes(&self) -> Resource<'_, T, NonInvestmentIncome, u32> {
+        Resource::new(self, "/non-investment-incomes/")
+    }
+
+    /// Spouse incomes listed on disclosures (`/spouse-incomes/`)
+    pub fn spouse_incomes(&self) -> Resource<'_, T, SpouseIncome, u32> {
+        Resource::new(self, "/spouse-incomes/")
+    }
+
+    /// Reimbursements listed on disclosures (`/reimbursements/`)
+    pub fn reimbursements(&self) -> Resource<'_, T, Reimbursement, u32> {
+        Resource::new(self, "/reimbursements/")
+    }
+
+    /// Gifts listed on disclosures (`/gifts/`)
+    pub fn gifts(&self) -> Resource<'_, T, Gift, u32> {
+        Resource::new(self, "/gifts/")
+    }
+
+    /// Debts listed on disclosures (`/debts/`)
+    pub fn debts(&self) -> Resource<'_, T, Debt, u32> {
+        Resource::new(self, "/debts/")
+    }
+
+    /// Oral argument audio (`/audio/`)
+    pub fn audio(&self) -> Resource<'_, T, Audio, AudioId> {
+        Resource::new(self, "/audio/")
+    }
+
+    /// Citation graph between opinions (`/opinions-cited/`)
+    pub fn opinions_cited(&self) -> Resource<'_, T, ApiCitation, CitationId> {
+        Resource::new(self, "/opinions-cited/")
+    }
+
+    /// Search (`/search/`)
+    pub fn search(&self) -> Search<'_, T> {
+        Search { client: self }
+    }
+}
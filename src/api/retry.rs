@@ -0,0 +1,246 @@
+//! Retry policy for upstream requests
+//!
+//! Idempotent requests that fail with a network error or a retryable status
+//! (408, 429, 500, 502, 503, 504) are retried with exponential backoff and
+//! jitter. A `Retry-After` on 429/503 replaces the computed backoff; if it asks
+//! for longer than `max_delay`, the error is returned instead of waiting.
+//! `send` takes a rate limit token per attempt; a `RateLimited` error ends the
+//! loop, since that attempt never reached the API.
+
+use crate::api::transport::{HttpMethod, HttpResponse};
+use crate::errors::{CourtListenerError, Result};
+use std::future::Future;
+use std::time::Duration;
+
+/// Statuses that are worth retrying
+const RETRYABLE_STATUSES: [u16; 6] = [408, 429, 500, 502, 503, 504];
+
+/// Retry settings for upstream requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first (1 disables retries)
+    pub max_attempts: u32,
+    /// Backoff before the first retry, doubled for each later retry
+    pub base_delay: Duration,
+    /// Upper bound on any single wait, including `Retry-After`
+    pub max_delay: Duration,
+    /// Randomize each backoff between half and all of its value
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default policy: 3 attempts, 500ms base backoff, 30s cap, jitter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Never retry
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Set the total number of attempts (at least 1)
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the backoff before the first retry
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the upper bound on any single wait
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enable or disable jitter
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Read the policy from worker vars, falling back to the defaults:
+    /// `RETRY_MAX_ATTEMPTS`, `RETRY_BASE_DELAY_MS`, `RETRY_MAX_DELAY_MS`
+    #[cfg(feature = "worker")]
+    pub fn from_env(env: &worker::Env) -> Self {
+        let var = |name: &str| {
+            env.var(name)
+                .ok()
+                .and_then(|v| v.to_string().trim().parse::<u64>().ok())
+        };
+        let mut policy = Self::default();
+        if let Some(n) = var("RETRY_MAX_ATTEMPTS") {
+            policy = policy.max_attempts(n.min(u32::MAX as u64) as u32);
+        }
+        if let Some(ms) = var("RETRY_BASE_DELAY_MS") {
+            policy = policy.base_delay(Duration::from_millis(ms));
+        }
+        if let Some(ms) = var("RETRY_MAX_DELAY_MS") {
+            policy = policy.max_delay(Duration::from_millis(ms));
+        }
+        policy
+    }
+
+    /// Whether requests with this method may be retried
+    pub fn is_retryable_method(&self, method: HttpMethod) -> bool {
+        !matches!(method, HttpMethod::Post | HttpMethod::Patch)
+    }
+
+    /// Whether a response status is worth retrying
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        RETRYABLE_STATUSES.contains(&status)
+    }
+
+    /// Wait before retry number `retry` (1-based), or None to stop retrying
+    /// `jitter` is a random value in [0, 1) and is ignored when jitter is off
+    pub fn delay(&self, retry: u32, retry_after: Option<u64>, jitter: f64) -> Option<Duration> {
+        if let Some(seconds) = retry_after {
+            let wait = Duration::from_secs(seconds);
+            return (wait <= self.max_delay).then_some(wait);
+        }
+        let exponent = retry.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if self.jitter {
+            Some(backoff.mul_f64(0.5 + jitter.clamp(0.0, 1.0) / 2.0))
+        } else {
+            Some(backoff)
+        }
+    }
+
+    /// Run `send` until it succeeds, fails permanently or runs out of attempts
+    /// `sleep` performs the waits between attempts. `RateLimited` from `send`
+    /// is returned at once and not counted as an attempt
+    pub async fn run<R, F, Fut, S, SFut>(
+        &self,
+        method: HttpMethod,
+        mut send: F,
+        sleep: S,
+    ) -> Retried<Result<R>>
+    where
+        R: RetryableResponse,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<R>>,
+        S: Fn(Duration) -> SFut,
+        SFut: Future<Output = ()>,
+    {
+        let max_attempts = if self.is_retryable_method(method) {
+            self.max_attempts.max(1)
+        } else {
+            1
+        };
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let result = send().await;
+            let retry_after = match &result {
+                Ok(resp) if self.is_retryable_status(resp.status()) => {
+                    // Retry-After is only meaningful on throttling and maintenance
+                    matches!(resp.status(), 429 | 503)
+                        .then(|| resp.retry_after())
+                        .flatten()
+                }
+                Ok(_) => return Retried { result, attempts },
+                Err(CourtListenerError::RateLimited(_)) => {
+                    return Retried {
+                        result,
+                        attempts: attempts - 1,
+                    }
+                }
+                Err(_) => None,
+            };
+            if attempts >= max_attempts {
+                return Retried { result, attempts };
+            }
+            match self.delay(attempts, retry_after, random_unit()) {
+                Some(wait) => sleep(wait).await,
+                None => return Retried { result, attempts },
+            }
+        }
+    }
+}
+
+/// Result of a retried operation and how many attempts it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Retried<T> {
+    pub result: T,
+    pub attempts: u32,
+}
+
+impl<T> Retried<T> {
+    /// Transform the result, keeping the attempt count
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Retried<U> {
+        Retried {
+            result: f(self.result),
+            attempts: self.attempts,
+        }
+    }
+}
+
+/// Response information needed to decide on a retry
+pub trait RetryableResponse {
+    fn status(&self) -> u16;
+
+    /// `Retry-After` in seconds (delta-seconds form only)
+    fn retry_after(&self) -> Option<u64>;
+}
+
+impl RetryableResponse for HttpResponse {
+    fn status(&self) -> u16 {
+        self.status
+    }
+
+    fn retry_after(&self) -> Option<u64> {
+        self.header("Retry-After")
+            .and_then(|v| v.trim().parse().ok())
+    }
+}
+
+#[cfg(feature = "worker")]
+impl RetryableResponse for worker::Response {
+    fn status(&self) -> u16 {
+        self.status_code()
+    }
+
+    fn retry_after(&self) -> Option<u64> {
+        self.headers()
+            .get("Retry-After")
+            .ok()
+            .flatten()
+            .and_then(|v| v.trim().parse().ok())
+    }
+}
+
+/// Random value in [0, 1) for jitter
+fn random_unit() -> f64 {
+    #[cfg(all(feature = "worker", target_arch = "wasm32"))]
+    {
+        worker::js_sys::Math::random()
+    }
+    #[cfg(not(all(feature = "worker", target_arch = "wasm32")))]
+    {
+        use std::hash::{BuildHasher, Hasher};
+        // Each RandomState is seeded differently, which is enough for jitter
+        let bits = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        (bits >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -0,0 +1,228 @@
+//! HTTP transport abstraction for the CourtListener client
+//!
+//! The client only needs to send a request and read back status, headers and
+//! body text, so the transport is kept to a single trait. Implementations:
+//! - `WorkerTransport`: `worker::Fetch` (requires the `worker` feature)
+//! - `ReqwestTransport`: native reqwest client (requires the `native` feature)
+
+use crate::errors::Result;
+use std::time::Duration;
+
+/// HTTP methods supported by the transport layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    Get,
+    Head,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Options,
+}
+
+impl HttpMethod {
+    /// Method name as used on the wire
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Head => "HEAD",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+            Self::Options => "OPTIONS",
+        }
+    }
+}
+
+/// Outgoing HTTP request
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    /// Create a request with no headers or body
+    pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Add a header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set the request body
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// HTTP response as seen by the client
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// Look up a header value (case-insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Whether the status code is in the 2xx range
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends HTTP requests on behalf of `CourtListenerClient`
+///
+/// Futures returned by the worker runtime are not `Send`, so the trait
+/// does not require it; native implementations still produce `Send` futures.
+#[allow(async_fn_in_trait)]
+pub trait HttpTransport {
+    /// Send a request and read the full response body
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+
+    /// Wait between retries and for rate limit tokens
+    /// Required, so backoff and `Retry-After` delays are never skipped
+    async fn sleep(&self, duration: Duration);
+}
+
+/// Transport backed by `worker::Fetch`
+#[cfg(feature = "worker")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorkerTransport;
+
+#[cfg(feature = "worker")]
+impl HttpTransport for WorkerTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        use worker::wasm_bindgen::JsValue;
+
+        let method = match request.method {
+            HttpMethod::Get => worker::Method::Get,
+            HttpMethod::Head => worker::Method::Head,
+            HttpMethod::Post => worker::Method::Post,
+            HttpMethod::Put => worker::Method::Put,
+            HttpMethod::Patch => worker::Method::Patch,
+            HttpMethod::Delete => worker::Method::Delete,
+            HttpMethod::Options => worker::Method::Options,
+        };
+
+        let headers = worker::Headers::new();
+        for (name, value) in &request.headers {
+            headers.set(name, value)?;
+        }
+
+        let init = worker::RequestInit {
+            method,
+            headers,
+            body: request.body.as_deref().map(JsValue::from_str),
+            ..Default::default()
+        };
+
+        let req = worker::Request::new_with_init(&request.url, &init)?;
+        let mut resp = worker::Fetch::Request(req).send().await?;
+
+        let status = resp.status_code();
+        let headers = resp.headers().entries().collect();
+        let body = resp.text().await?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        worker::Delay::from(duration).await;
+    }
+}
+
+/// Transport backed by a native reqwest client
+#[cfg(feature = "native")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "native")]
+impl ReqwestTransport {
+    /// Create a transport with a default reqwest client
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a transport from an existing reqwest client (timeouts, proxies, etc.)
+    pub fn with_client(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "native")]
+impl HttpTransport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        use crate::errors::CourtListenerError;
+
+        let method = match request.method {
+            HttpMethod::Get => reqwest::Method::GET,
+            HttpMethod::Head => reqwest::Method::HEAD,
+            HttpMethod::Post => reqwest::Method::POST,
+            HttpMethod::Put => reqwest::Method::PUT,
+            HttpMethod::Patch => reqwest::Method::PATCH,
+            HttpMethod::Delete => reqwest::Method::DELETE,
+            HttpMethod::Options => reqwest::Method::OPTIONS,
+        };
+
+        let mut builder = self.client.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let resp = builder
+            .send()
+            .await
+            .map_err(|e| CourtListenerError::Http(e.to_string()))?;
+
+        let status = resp.status().as_u16();
+        let headers = resp
+            .headers()
+            .iter()
+            .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.to_string(), v.to_string())))
+            .collect();
+        let body = resp
+            .text()
+            .await
+            .map_err(|e| CourtListenerError::Http(e.to_string()))?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
@@ -0,0 +1,94 @@
+//! Cache administration: purging and inspecting entries
+
+use super::entry::{is_chunk_key, StoredEntry};
+use super::{generate_cache_key, CacheBackend};
+use crate::errors::Result;
+use crate::time::now_millis;
+use serde::Serialize;
+
+/// Age and expiry of a cached entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EntryInfo {
+    pub key: String,
+    /// "fresh", "stale", "expired" or "legacy" (no metadata)
+    pub state: &'static str,
+    /// Seconds since the entry was stored
+    pub age: Option<u64>,
+    pub ttl: Option<u64>,
+    pub max_stale: Option<u64>,
+    /// Stored size of the entry's first value in bytes
+    pub size: usize,
+    /// Body encoding ("identity", "gzip" or "br")
+    pub encoding: Option<&'static str>,
+    /// Decoded body size in bytes
+    pub data_size: Option<usize>,
+    /// Number of values the body is split across
+    pub chunks: Option<u32>,
+    /// Upstream status of a negative (cached error) entry
+    pub status: Option<u16>,
+}
+
+/// Describe the entry stored under `key`, if any
+pub async fn inspect_entry<C: CacheBackend>(cache: &C, key: &str) -> Result<Option<EntryInfo>> {
+    let Some(bytes) = cache.get(key).await? else {
+        return Ok(None);
+    };
+    let size = bytes.len();
+    let info = match StoredEntry::parse(bytes) {
+        Some(StoredEntry::Entry { header, .. }) => {
+            let age = header.age(now_millis() / 1000);
+            let state = if age <= header.ttl {
+                "fresh"
+            } else if age <= header.ttl.saturating_add(header.max_stale) {
+                "stale"
+            } else {
+                "expired"
+            };
+            EntryInfo {
+                key: key.to_string(),
+                state,
+                age: Some(age),
+                ttl: Some(header.ttl),
+                max_stale: Some(header.max_stale),
+                size,
+                encoding: Some(header.encoding.as_str()),
+                data_size: Some(header.size),
+                chunks: Some(header.chunks),
+                status: header.status,
+            }
+        }
+        Some(StoredEntry::Legacy(_)) | None => EntryInfo {
+            key: key.to_string(),
+            state: "legacy",
+            age: None,
+            ttl: None,
+            max_stale: None,
+            size,
+            encoding: None,
+            data_size: None,
+            chunks: None,
+            status: None,
+        },
+    };
+    Ok(Some(info))
+}
+
+/// Delete every entry whose key starts with `prefix`, returning how many were removed
+/// Chunks of large entries share their entry's prefix and are removed too,
+/// but not counted
+pub async fn purge_prefix<C: CacheBackend>(cache: &C, prefix: &str) -> Result<usize> {
+    let keys = cache.list(prefix).await?;
+    for key in &keys {
+        cache.delete(key).await?;
+    }
+    Ok(keys.iter().filter(|key| !is_chunk_key(key)).count())
+}
+
+/// Key prefix covering every cached variant of one resource, such as
+/// `resource_prefix("opinions", "123")` for `/opinions/123/` with any query
+/// or partition
+pub fn resource_prefix(resource: &str, id: &str) -> String {
+    let resource = resource.trim_matches('/');
+    let id = id.trim_matches('/');
+    generate_cache_key(&format!("/{}/{}/", resource, id), None)
+}
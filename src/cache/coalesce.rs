@@ -0,0 +1,120 @@
+//! Single-flight coalescing of identical upstream fetches
+//!
+//! Concurrent cache misses for the same key share one upstream call: the first
+//! caller fetches in its own request and later callers wait for the result.
+//! Only the finished value (plain data) is handed to the waiting requests, so
+//! no request ever touches another request's I/O objects, which Workers
+//! forbids. If the first caller goes away before finishing, the waiting
+//! callers fetch on their own. Workers run each isolate on one thread, so the
+//! in-flight table is not `Send`.
+
+use futures_channel::oneshot;
+use futures_util::future::{FutureExt, Shared};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+
+/// How concurrent identical fetches are coalesced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Coalescing {
+    /// Every request fetches on its own
+    Off,
+    /// Requests in the same isolate share a fetch
+    #[default]
+    Isolate,
+    /// Requests in every isolate share a fetch through a Durable Object
+    /// (bound as `COALESCER`), falling back to the isolate
+    DurableObject,
+}
+
+impl Coalescing {
+    /// Parse a `CACHE_COALESCE` value: "off", "isolate" or "durable-object"
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "off" | "none" | "false" => Some(Self::Off),
+            "isolate" | "memory" | "true" => Some(Self::Isolate),
+            "durable-object" | "durable_object" | "do" => Some(Self::DurableObject),
+            _ => None,
+        }
+    }
+}
+
+/// Receiving end of a fetch's result; `Err` when its caller went away
+type Flight<V> = Shared<oneshot::Receiver<V>>;
+
+/// Table of in-flight fetches keyed by cache key; clones share the table
+pub struct SingleFlight<V> {
+    inflight: Rc<RefCell<HashMap<String, Flight<V>>>>,
+}
+
+impl<V> Clone for SingleFlight<V> {
+    fn clone(&self) -> Self {
+        Self {
+            inflight: Rc::clone(&self.inflight),
+        }
+    }
+}
+
+impl<V> Default for SingleFlight<V> {
+    fn default() -> Self {
+        Self {
+            inflight: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+}
+
+/// Removes a flight from the table when its caller finishes or is dropped
+struct FlightGuard<'a, V> {
+    inflight: &'a RefCell<HashMap<String, Flight<V>>>,
+    key: &'a str,
+}
+
+impl<V> Drop for FlightGuard<'_, V> {
+    fn drop(&mut self) {
+        self.inflight.borrow_mut().remove(self.key);
+    }
+}
+
+impl<V: Clone> SingleFlight<V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of fetches currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.inflight.borrow().len()
+    }
+
+    /// Run `fetch` for `key`, or wait for the fetch already running for it
+    /// Returns the value and whether it came from another caller's fetch.
+    /// `fetch` always runs in the calling request: either this caller started
+    /// the flight, or the caller that did was dropped before finishing
+    pub async fn run<F, Fut>(&self, key: &str, fetch: F) -> (V, bool)
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        let existing = self.inflight.borrow().get(key).cloned();
+        if let Some(flight) = existing {
+            return match flight.await {
+                Ok(value) => (value, true),
+                Err(oneshot::Canceled) => (fetch().await, false),
+            };
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        self.inflight
+            .borrow_mut()
+            .insert(key.to_string(), receiver.shared());
+        let guard = FlightGuard {
+            inflight: &self.inflight,
+            key,
+        };
+        let value = fetch().await;
+        // Later callers start a new flight rather than join a finished one
+        drop(guard);
+        let _ = sender.send(value.clone());
+        (value, false)
+    }
+}
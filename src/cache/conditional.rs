@@ -0,0 +1,172 @@
+//! Conditional request support (`ETag` / `Last-Modified`)
+//!
+//! Responses carry a strong ETag computed from the body and, where the
+//! resource has a `date_modified`, a `Last-Modified` date. Requests with a
+//! matching `If-None-Match` or a satisfied `If-Modified-Since` get a 304.
+//! The ETag depends on the body, so the check happens after the response is
+//! built: a 304 saves bandwidth, not the cache read or upstream fetch.
+
+use serde_json::Value;
+
+const DAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Strong ETag for a response body (quoted, 128-bit SHA-256 prefix)
+pub fn etag_for(body: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(body);
+    let hex: String = digest[..16].iter().map(|b| format!("{:02x}", b)).collect();
+    format!("\"{}\"", hex)
+}
+
+/// Last modification time of a resource in seconds since the epoch
+/// Uses `date_modified`, or the latest `date_modified` of a page of `results`
+pub fn last_modified_of(value: &Value) -> Option<u64> {
+    let date_modified = |v: &Value| {
+        v.get("date_modified")
+            .and_then(Value::as_str)
+            .and_then(parse_iso8601)
+    };
+    date_modified(value).or_else(|| {
+        value
+            .get("results")?
+            .as_array()?
+            .iter()
+            .filter_map(date_modified)
+            .max()
+    })
+}
+
+/// Whether a request's validators show the client already has the response
+/// `If-None-Match` takes precedence; `If-Modified-Since` is only checked without it
+pub fn is_not_modified(
+    if_none_match: Option<&str>,
+    if_modified_since: Option<&str>,
+    etag: Option<&str>,
+    last_modified: Option<u64>,
+) -> bool {
+    if let Some(if_none_match) = if_none_match {
+        return etag.is_some_and(|etag| etag_matches(if_none_match, etag));
+    }
+    match (if_modified_since.and_then(parse_http_date), last_modified) {
+        (Some(since), Some(modified)) => modified <= since,
+        _ => false,
+    }
+}
+
+/// Weak comparison of an `If-None-Match` list against an ETag
+fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+    if_none_match
+        .split(',')
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == etag)
+}
+
+/// Format seconds since the epoch as an HTTP date (IMF-fixdate)
+pub fn format_http_date(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+        DAYS[days.rem_euclid(7) as usize],
+        day,
+        MONTHS[(month - 1) as usize],
+        year,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Parse an HTTP date in IMF-fixdate form (`Sun, 06 Nov 1994 08:49:37 GMT`)
+pub fn parse_http_date(value: &str) -> Option<u64> {
+    let (_, rest) = value.trim().split_once(", ")?;
+    let mut parts = rest.split_whitespace();
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month_name = parts.next()?;
+    let month = MONTHS.iter().position(|m| *m == month_name)? as u32 + 1;
+    let year: i64 = parts.next()?.parse().ok()?;
+    let seconds = parse_time(parts.next()?)?;
+    if parts.next()? != "GMT" {
+        return None;
+    }
+    to_epoch(year, month, day, seconds, 0)
+}
+
+/// Parse an ISO 8601 date or datetime as returned by the API
+/// (`2024-01-05`, `2024-01-05T12:34:56.789-08:00`); a missing offset means UTC
+pub fn parse_iso8601(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (date, time) = match value.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+    let mut ymd = date.split('-');
+    let year: i64 = ymd.next()?.parse().ok()?;
+    let month: u32 = ymd.next()?.parse().ok()?;
+    let day: u32 = ymd.next()?.parse().ok()?;
+    let Some(time) = time else {
+        return to_epoch(year, month, day, 0, 0);
+    };
+
+    // Split off the UTC offset
+    let (clock, offset) = if let Some(clock) = time.strip_suffix('Z') {
+        (clock, 0)
+    } else if let Some(idx) = time.rfind(['+', '-']) {
+        let (clock, offset) = time.split_at(idx);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_once(':').unwrap_or((&offset[1..], "0"));
+        let minutes = hours.parse::<i64>().ok()? * 60 + minutes.parse::<i64>().ok()?;
+        (clock, sign * minutes * 60)
+    } else {
+        (time, 0)
+    };
+    let clock = clock.split('.').next()?;
+    to_epoch(year, month, day, parse_time(clock)?, offset)
+}
+
+/// Parse `HH:MM[:SS]` into seconds since midnight
+fn parse_time(value: &str) -> Option<u64> {
+    let mut hms = value.split(':');
+    let hours: u64 = hms.next()?.parse().ok()?;
+    let minutes: u64 = hms.next()?.parse().ok()?;
+    let seconds: u64 = hms.next().map_or(Some(0), |s| s.parse().ok())?;
+    (hours < 24 && minutes < 60 && seconds < 61).then_some(hours * 3600 + minutes * 60 + seconds)
+}
+
+fn to_epoch(year: i64, month: u32, day: u32, seconds: u64, offset: i64) -> Option<u64> {
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let secs = days_from_civil(year, month, day) * 86_400 + seconds as i64 - offset;
+    u64::try_from(secs).ok()
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Proleptic Gregorian date for days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,74 @@
+//! Workers Cache API backend
+//!
+//! The Cache API is local to each Cloudflare data center and free to use, but
+//! entries are not shared between locations. Keys must be URLs, so cache keys
+//! are mapped onto a private host that is never fetched.
+
+use super::CacheBackend;
+use crate::errors::{CourtListenerError, Result};
+use worker::{Cache, Response};
+
+/// Host used to turn cache keys into Cache API URLs
+const KEY_HOST: &str = "https://cache.courtlistener-worker.internal/";
+
+/// Cache backed by the data center's default Cache API store
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EdgeCache;
+
+impl EdgeCache {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Cache API URL for a cache key
+    pub fn url_for(key: &str) -> String {
+        let mut url = String::with_capacity(KEY_HOST.len() + key.len());
+        url.push_str(KEY_HOST);
+        for byte in key.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                    url.push(byte as char)
+                }
+                _ => url.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+        url
+    }
+}
+
+fn cache_error(e: worker::Error) -> CourtListenerError {
+    CourtListenerError::Cache(e.to_string())
+}
+
+impl CacheBackend for EdgeCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let cached = Cache::default()
+            .get(Self::url_for(key), true)
+            .await
+            .map_err(cache_error)?;
+        match cached {
+            Some(mut resp) => Ok(Some(resp.bytes().await.map_err(cache_error)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn put(&self, key: &str, value: &[u8], ttl: u64) -> Result<()> {
+        let mut resp = Response::from_bytes(value.to_vec()).map_err(cache_error)?;
+        // The Cache API expires entries based on the stored response headers
+        resp.headers_mut()
+            .set("Cache-Control", &format!("public, max-age={}", ttl))
+            .map_err(cache_error)?;
+        Cache::default()
+            .put(Self::url_for(key), resp)
+            .await
+            .map_err(cache_error)
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        Cache::default()
+            .delete(Self::url_for(key), true)
+            .await
+            .map(|_| ())
+            .map_err(cache_error)
+    }
+}
@@ -0,0 +1,300 @@
+//! Cache entry storage format
+//!
+//! Entries are stored as a magic line, a JSON header line and the (optionally
+//! compressed) body:
+//!
+//! ```text
+//! clce1
+//! {"timestamp":1700000000,"ttl":1800,"max_stale":3600,"encoding":"gzip","size":5242880,"chunks":1,"id":"9f2c01d4"}
+//! <body bytes>
+//! ```
+//!
+//! Bodies larger than the chunk size are split: the first chunk is stored with
+//! the header and the rest under `{key}#chunk:{id}:{n}`, so prefix purges remove
+//! them together. Entries written as JSON (`{"data": ..., "timestamp": ...}`)
+//! and plain strings from before this format are still read.
+
+use super::CacheBackend;
+use crate::errors::{CourtListenerError, Result};
+use crate::time::now_millis;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+/// Workers KV value size limit in bytes
+pub const KV_VALUE_LIMIT: usize = 25 * 1024 * 1024;
+
+/// Bodies smaller than this are stored uncompressed
+pub const MIN_COMPRESS_SIZE: usize = 1024;
+
+/// First line of entries in this format
+const MAGIC: &[u8] = b"clce1\n";
+
+/// How a cached body is encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ContentEncoding {
+    #[default]
+    #[serde(rename = "identity")]
+    Identity,
+    #[serde(rename = "gzip")]
+    Gzip,
+    #[serde(rename = "br")]
+    Brotli,
+}
+
+impl ContentEncoding {
+    /// Parse a `CACHE_COMPRESSION` value: "gzip", "br" (or "brotli") or "none"
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "gzip" => Some(Self::Gzip),
+            "br" | "brotli" => Some(Self::Brotli),
+            "none" | "identity" | "off" => Some(Self::Identity),
+            _ => None,
+        }
+    }
+
+    /// Name as used in `Content-Encoding`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Identity => "identity",
+            Self::Gzip => "gzip",
+            Self::Brotli => "br",
+        }
+    }
+
+    /// Compress `data` with this encoding
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        match self {
+            Self::Identity => out.extend_from_slice(data),
+            Self::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(&mut out, flate2::Compression::default());
+                encoder.write_all(data).map_err(compression_error)?;
+                encoder.finish().map_err(compression_error)?;
+            }
+            Self::Brotli => {
+                // Quality 5 keeps compression fast enough for the request path
+                let mut encoder = brotli::CompressorWriter::new(&mut out, 4096, 5, 22);
+                encoder.write_all(data).map_err(compression_error)?;
+                // Finishes the stream
+                encoder.into_inner();
+            }
+        }
+        Ok(out)
+    }
+
+    /// Decompress `data` encoded with this encoding
+    pub fn decode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        match self {
+            Self::Identity => out.extend_from_slice(data),
+            Self::Gzip => {
+                flate2::read::GzDecoder::new(data)
+                    .read_to_end(&mut out)
+                    .map_err(compression_error)?;
+            }
+            Self::Brotli => {
+                brotli::Decompressor::new(data, 4096)
+                    .read_to_end(&mut out)
+                    .map_err(compression_error)?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+fn compression_error(e: std::io::Error) -> CourtListenerError {
+    CourtListenerError::Cache(format!("cache entry compression failed: {}", e))
+}
+
+/// How entries are written: compression and maximum stored value size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntryStorage {
+    pub encoding: ContentEncoding,
+    /// Maximum bytes of body stored under one key
+    pub chunk_size: usize,
+}
+
+impl Default for EntryStorage {
+    fn default() -> Self {
+        Self {
+            encoding: ContentEncoding::Gzip,
+            // Leave room for the header
+            chunk_size: KV_VALUE_LIMIT - 64 * 1024,
+        }
+    }
+}
+
+/// Entry metadata stored before the body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EntryHeader {
+    /// Seconds since the epoch when the entry was stored
+    pub timestamp: u64,
+    pub ttl: u64,
+    /// Seconds past `ttl` during which the entry may still be served stale
+    #[serde(default)]
+    pub max_stale: u64,
+    #[serde(default)]
+    pub encoding: ContentEncoding,
+    /// Decoded body size in bytes
+    #[serde(default)]
+    pub size: usize,
+    /// Number of chunks, including the one stored with the header
+    #[serde(default = "one")]
+    pub chunks: u32,
+    /// Hash of the encoded body, naming its chunks
+    #[serde(default)]
+    pub id: String,
+    /// Upstream status of a cached error (negative entry); None for data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+}
+
+fn one() -> u32 {
+    1
+}
+
+impl EntryHeader {
+    pub fn age(&self, now: u64) -> u64 {
+        now.saturating_sub(self.timestamp)
+    }
+
+    /// Keys of the chunks stored apart from the header
+    pub fn chunk_keys(&self, key: &str) -> Vec<String> {
+        (1..self.chunks)
+            .map(|n| format!("{}#chunk:{}:{}", key, self.id, n))
+            .collect()
+    }
+}
+
+/// Entry format before headers and compression
+#[derive(Deserialize)]
+struct JsonEntry {
+    data: String,
+    timestamp: u64,
+    ttl: u64,
+    #[serde(default)]
+    max_stale: u64,
+}
+
+/// A value read from the cache
+pub(crate) enum StoredEntry {
+    /// Plain value without metadata
+    Legacy(String),
+    /// Header and the first chunk of the encoded body
+    Entry { header: EntryHeader, body: Vec<u8> },
+}
+
+impl StoredEntry {
+    /// Parse a stored value; None if it is unreadable
+    pub fn parse(bytes: Vec<u8>) -> Option<Self> {
+        if let Some(rest) = bytes.strip_prefix(MAGIC) {
+            let split = rest.iter().position(|&b| b == b'\n')?;
+            let header: EntryHeader = serde_json::from_slice(&rest[..split]).ok()?;
+            let body = rest[split + 1..].to_vec();
+            return Some(Self::Entry { header, body });
+        }
+        let text = String::from_utf8(bytes).ok()?;
+        match serde_json::from_str::<JsonEntry>(&text) {
+            Ok(entry) => Some(Self::Entry {
+                header: EntryHeader {
+                    timestamp: entry.timestamp,
+                    ttl: entry.ttl,
+                    max_stale: entry.max_stale,
+                    encoding: ContentEncoding::Identity,
+                    size: entry.data.len(),
+                    chunks: 1,
+                    id: String::new(),
+                    status: None,
+                },
+                body: entry.data.into_bytes(),
+            }),
+            Err(_) => Some(Self::Legacy(text)),
+        }
+    }
+}
+
+/// Reassemble and decode a body, reading its remaining chunks from `cache`
+/// None if a chunk is missing or the body does not decode
+pub(crate) async fn read_body<C: CacheBackend>(
+    cache: &C,
+    key: &str,
+    header: &EntryHeader,
+    mut body: Vec<u8>,
+) -> Option<String> {
+    for chunk_key in header.chunk_keys(key) {
+        body.extend(cache.get(&chunk_key).await.ok().flatten()?);
+    }
+    let decoded = header.encoding.decode(&body).ok()?;
+    if decoded.len() != header.size {
+        return None;
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// An encoded entry: the value stored under the entry's key and any extra chunks
+pub(crate) struct EncodedEntry {
+    pub primary: Vec<u8>,
+    pub chunks: Vec<(String, Vec<u8>)>,
+}
+
+/// Encode a value for storage under `key`
+/// `status` marks a negative entry holding an upstream error body
+pub(crate) fn encode_entry(
+    key: &str,
+    value: &str,
+    ttl: u64,
+    max_stale: u64,
+    storage: &EntryStorage,
+    status: Option<u16>,
+) -> Result<EncodedEntry> {
+    let mut encoding = if value.len() < MIN_COMPRESS_SIZE {
+        ContentEncoding::Identity
+    } else {
+        storage.encoding
+    };
+    let mut body = encoding.encode(value.as_bytes())?;
+    if body.len() >= value.len() {
+        encoding = ContentEncoding::Identity;
+        body = value.as_bytes().to_vec();
+    }
+
+    let chunk_size = storage.chunk_size.max(1);
+    let mut chunks = body.chunks(chunk_size);
+    let first = chunks.next().unwrap_or_default();
+    let header = EntryHeader {
+        timestamp: now_millis() / 1000,
+        ttl,
+        max_stale,
+        encoding,
+        size: value.len(),
+        chunks: body.len().div_ceil(chunk_size).max(1) as u32,
+        id: body_id(&body),
+        status,
+    };
+    let rest = header.chunk_keys(key).into_iter().zip(chunks);
+    let rest = rest.map(|(key, chunk)| (key, chunk.to_vec())).collect();
+
+    let mut primary = MAGIC.to_vec();
+    primary.extend(serde_json::to_vec(&header)?);
+    primary.push(b'\n');
+    primary.extend_from_slice(first);
+    Ok(EncodedEntry {
+        primary,
+        chunks: rest,
+    })
+}
+
+/// Short content hash, so chunks of different versions never mix
+fn body_id(body: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(body)[..4]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Whether a key names a chunk rather than an entry
+pub fn is_chunk_key(key: &str) -> bool {
+    key.contains("#chunk:")
+}
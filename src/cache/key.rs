@@ -0,0 +1,87 @@
+//! Canonical cache keys
+//!
+//! Keys are `v{CACHE_KEY_VERSION}:{endpoint}` plus a canonical query: pairs are
+//! percent-decoded and re-encoded the same way, parameters that do not change
+//! the response are dropped, and the rest are sorted. Long queries are replaced
+//! by a SHA-256 digest. Bumping `CACHE_KEY_VERSION` orphans every old entry.
+
+use crate::query::encode;
+
+/// Key schema version; bump when the key format or cached representation changes
+pub const CACHE_KEY_VERSION: u32 = 1;
+
+/// Canonical queries longer than this are hashed to stay within KV key limits
+const MAX_QUERY_LEN: usize = 200;
+
+/// Parameters with values that match the API default
+const NO_OP_PARAMS: [(&str, &str); 3] = [("page", "1"), ("page_size", "20"), ("format", "json")];
+
+/// Generate a cache key from endpoint and query parameters
+pub fn generate_cache_key(endpoint: &str, query: Option<&str>) -> String {
+    let base_key = format!(
+        "v{}:{}",
+        CACHE_KEY_VERSION,
+        endpoint.trim_start_matches('/')
+    );
+    let query = canonical_query(query.unwrap_or_default());
+    if query.is_empty() {
+        base_key
+    } else if query.len() > MAX_QUERY_LEN {
+        use sha2::{Digest, Sha256};
+        let digest = Sha256::digest(query.as_bytes());
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("{}:q:{}", base_key, hex)
+    } else {
+        format!("{}:{}", base_key, query)
+    }
+}
+
+/// Normalize a query string so equivalent queries compare equal
+/// Empty values and API defaults are dropped and the pairs are sorted
+pub fn canonical_query(query: &str) -> String {
+    let mut pairs: Vec<(String, String)> = query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(key), decode(value))
+        })
+        .filter(|(key, value)| {
+            !key.is_empty()
+                && !value.is_empty()
+                && !NO_OP_PARAMS.contains(&(key.as_str(), value.as_str()))
+        })
+        .collect();
+    pairs.sort();
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Percent-decode a query component (`+` is a space); invalid escapes are kept as-is
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = |b: u8| (b as char).to_digit(16);
+                match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                    (Some(high), Some(low)) => {
+                        out.push((high * 16 + low) as u8);
+                        i += 2;
+                    }
+                    _ => out.push(b'%'),
+                }
+            }
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
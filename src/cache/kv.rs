@@ -0,0 +1,92 @@
+//! Workers KV cache backend
+//!
+//! KV is eventually consistent and global; writes may take up to a minute to
+//! reach other locations. Expiration is handled by KV itself.
+
+use super::CacheBackend;
+use crate::errors::{CourtListenerError, Result};
+use worker::kv::KvStore;
+
+/// Cache backed by a Workers KV namespace
+#[derive(Clone)]
+pub struct KvCache {
+    kv: KvStore,
+}
+
+impl KvCache {
+    /// Wrap a KV namespace
+    pub fn new(kv: KvStore) -> Self {
+        Self { kv }
+    }
+
+    /// Use the KV namespace bound as `binding`
+    pub fn from_env(env: &worker::Env, binding: &str) -> Result<Self> {
+        env.kv(binding)
+            .map(Self::new)
+            .map_err(|e| CourtListenerError::Cache(e.to_string()))
+    }
+}
+
+impl CacheBackend for KvCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match self.kv.get(key).bytes().await {
+            Ok(Some(bytes)) => {
+                worker::console_log!("Cache HIT: {}", key);
+                Ok(Some(bytes))
+            }
+            Ok(None) => {
+                worker::console_log!("Cache MISS: {}", key);
+                Ok(None)
+            }
+            Err(e) => {
+                worker::console_log!("Cache ERROR: {} - {}", key, e);
+                Err(CourtListenerError::Cache(e.to_string()))
+            }
+        }
+    }
+
+    async fn put(&self, key: &str, value: &[u8], ttl: u64) -> Result<()> {
+        // KV rejects TTLs below 60 seconds
+        let result = match self.kv.put_bytes(key, value) {
+            Ok(builder) => builder.expiration_ttl(ttl.max(60)).execute().await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => {
+                worker::console_log!("Cached response: {} (TTL: {}s)", key, ttl);
+                Ok(())
+            }
+            Err(e) => {
+                worker::console_log!("Failed to cache response: {} - {}", key, e);
+                Err(CourtListenerError::Cache(e.to_string()))
+            }
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.kv
+            .delete(key)
+            .await
+            .map_err(|e| CourtListenerError::Cache(e.to_string()))
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut cursor = None;
+        loop {
+            let mut builder = self.kv.list().prefix(prefix.to_string());
+            if let Some(cursor) = cursor.take() {
+                builder = builder.cursor(cursor);
+            }
+            let page = builder
+                .execute()
+                .await
+                .map_err(|e| CourtListenerError::Cache(e.to_string()))?;
+            keys.extend(page.keys.into_iter().map(|key| key.name));
+            match page.cursor {
+                Some(next) if !page.list_complete => cursor = Some(next),
+                _ => return Ok(keys),
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! In-memory LRU cache backend
+//!
+//! Entries live for the lifetime of the process (or worker isolate). Once the
+//! capacity is reached the least recently used entry is evicted.
+
+use super::CacheBackend;
+use crate::errors::Result;
+use crate::time::now_millis;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+struct Entry {
+    value: Vec<u8>,
+    expires_ms: u64,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct Lru {
+    entries: HashMap<String, Entry>,
+    /// Use counter -> key, oldest first
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Lru {
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.order.remove(&entry.last_used);
+            entry.last_used = self.tick;
+            self.order.insert(self.tick, key.to_string());
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.last_used);
+        }
+    }
+}
+
+/// In-memory LRU cache, cheap to clone (clones share entries)
+#[derive(Clone)]
+pub struct MemoryCache {
+    inner: Arc<Mutex<Lru>>,
+    capacity: usize,
+}
+
+impl Default for MemoryCache {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+impl MemoryCache {
+    /// Cache holding at most `capacity` entries (at least 1)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Lru::default())),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Maximum number of entries
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of entries currently stored, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether the cache holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove every entry
+    pub fn clear(&self) {
+        *self.lock() = Lru::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru> {
+        // A poisoned cache is still usable; entries are plain data
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Read a value at `now_ms`
+    pub fn get_at(&self, key: &str, now_ms: u64) -> Option<Vec<u8>> {
+        let mut lru = self.lock();
+        let expired = lru.entries.get(key)?.expires_ms <= now_ms;
+        if expired {
+            lru.remove(key);
+            return None;
+        }
+        lru.touch(key);
+        lru.entries.get(key).map(|entry| entry.value.clone())
+    }
+
+    /// Store a value for `ttl` seconds starting at `now_ms`
+    pub fn put_at(&self, key: &str, value: &[u8], ttl: u64, now_ms: u64) {
+        let mut lru = self.lock();
+        lru.remove(key);
+        while lru.entries.len() >= self.capacity {
+            match lru.order.pop_first() {
+                Some((_, oldest)) => {
+                    lru.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+        lru.entries.insert(
+            key.to_string(),
+            Entry {
+                value: value.to_vec(),
+                expires_ms: now_ms.saturating_add(ttl.saturating_mul(1000)),
+                last_used: 0,
+            },
+        );
+        lru.touch(key);
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self.get_at(key, now_millis()))
+    }
+
+    async fn put(&self, key: &str, value: &[u8], ttl: u64) -> Result<()> {
+        self.put_at(key, value, ttl, now_millis());
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.lock().remove(key);
+        Ok(())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut keys: Vec<String> = self
+            .lock()
+            .entries
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+}
@@ -0,0 +1,582 @@
+//! Caching utilities for the CourtListener Worker
+//!
+//! Provides:
+//! - `CacheBackend`: get / put-with-TTL / delete over raw bytes
+//! - Backends: in-memory LRU (`MemoryCache`), no-op (`NoopCache`), and with the
+//!   worker feature Workers KV (`KvCache`) and the Workers Cache API (`EdgeCache`)
+//! - Expiry-checked entries with stale-while-revalidate (`lookup_entry` / `put_entry`),
+//!   compressed and chunked to fit backend value limits
+//! - Negative caching of upstream 404s and empty results with a shorter TTL
+//! - Single-flight coalescing of concurrent identical upstream fetches
+//! - HTTP cache header management, including ETag / Last-Modified validators
+//! - Canonical, versioned cache key generation
+//! - TTL policy: per-endpoint rules from worker vars, honouring `max_ttl`
+//! - Auth-aware partitioning of user-scoped responses
+//! - Administration: purge by key, prefix or resource and entry inspection
+
+pub mod admin;
+pub mod coalesce;
+pub mod conditional;
+pub mod entry;
+pub mod key;
+pub mod memory;
+pub mod policy;
+pub mod scope;
+
+#[cfg(feature = "worker")]
+pub mod edge;
+#[cfg(feature = "worker")]
+pub mod kv;
+
+pub use admin::{inspect_entry, purge_prefix, resource_prefix, EntryInfo};
+pub use coalesce::{Coalescing, SingleFlight};
+pub use entry::{ContentEncoding, EntryStorage, KV_VALUE_LIMIT};
+pub use key::{canonical_query, generate_cache_key, CACHE_KEY_VERSION};
+pub use memory::MemoryCache;
+pub use policy::{default_ttl_rules, parse_ttl_rules, CacheDirectives, TtlPolicyConfig, TtlRule};
+pub use scope::{CachePartition, CacheScope, UserScopedCaching};
+
+#[cfg(feature = "worker")]
+pub use edge::EdgeCache;
+#[cfg(feature = "worker")]
+pub use kv::KvCache;
+
+use crate::errors::{CourtListenerError, Result};
+use crate::time::now_millis;
+use entry::{encode_entry, read_body, EncodedEntry, StoredEntry};
+
+/// Storage for cached API responses
+///
+/// Values are opaque bytes; `ttl` is in seconds. Backends may drop entries
+/// early, so a miss is always acceptable. Futures are not required to be
+/// `Send`, matching the worker runtime.
+#[allow(async_fn_in_trait)]
+pub trait CacheBackend {
+    /// Read a value, or None if it is missing or expired
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Store a value for `ttl` seconds
+    async fn put(&self, key: &str, value: &[u8], ttl: u64) -> Result<()>;
+
+    /// Remove a value (missing keys are not an error)
+    async fn delete(&self, key: &str) -> Result<()>;
+
+    /// Keys starting with `prefix`
+    /// Backends that cannot enumerate their keys (the Cache API) return an error
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let _ = prefix;
+        Err(CourtListenerError::Cache(
+            "this cache backend cannot list keys".to_string(),
+        ))
+    }
+}
+
+/// Backend that never stores anything
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopCache;
+
+impl CacheBackend for NoopCache {
+    async fn get(&self, _key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    async fn put(&self, _key: &str, _value: &[u8], _ttl: u64) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete(&self, _key: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list(&self, _prefix: &str) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Which cache backend to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheBackendKind {
+    /// Workers KV namespace bound as `CACHE`
+    #[default]
+    Kv,
+    /// Workers Cache API (per data center)
+    CacheApi,
+    /// In-memory LRU (per process or isolate)
+    Memory,
+    /// Caching disabled
+    None,
+}
+
+impl CacheBackendKind {
+    /// Parse a `CACHE_BACKEND` value: "kv", "cache-api", "memory" or "none"
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "kv" => Some(Self::Kv),
+            "cache-api" | "cache_api" | "cacheapi" => Some(Self::CacheApi),
+            "memory" => Some(Self::Memory),
+            "none" | "off" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// Name as accepted by `parse`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Kv => "kv",
+            Self::CacheApi => "cache-api",
+            Self::Memory => "memory",
+            Self::None => "none",
+        }
+    }
+}
+
+/// How a response was served, reported in `X-Cache`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// Served from a fresh cache entry
+    Hit,
+    /// Served from an expired entry while it is refreshed in the background
+    Stale,
+    /// Fetched from upstream
+    Miss,
+    /// Fetched from upstream without consulting or filling the cache
+    Bypass,
+}
+
+impl CacheStatus {
+    /// `X-Cache` header value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hit => "HIT",
+            Self::Stale => "STALE",
+            Self::Miss => "MISS",
+            Self::Bypass => "BYPASS",
+        }
+    }
+}
+
+/// Cache configuration
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    /// Default TTL in seconds (10 minutes)
+    pub default_ttl: u64,
+    /// Maximum TTL in seconds (1 hour)
+    pub max_ttl: u64,
+    /// Cache backend
+    pub backend: CacheBackendKind,
+    /// Maximum entries kept by the in-memory backend
+    pub memory_capacity: usize,
+    /// Seconds an expired entry may still be served while it is refreshed
+    pub max_stale: u64,
+    /// How user-scoped endpoints are cached
+    pub user_scoped: UserScopedCaching,
+    /// Cache requests that carry their own `Authorization` header (per credential)
+    pub cache_authorized: bool,
+    /// Per-endpoint TTLs, first match wins; unmatched endpoints use `default_ttl`
+    pub ttl_rules: Vec<TtlRule>,
+    /// Let requests skip cached copies with `Cache-Control: no-cache` / `no-store`
+    /// Off by default, since any client could then force upstream fetches
+    pub honor_no_cache: bool,
+    /// Entry compression and chunking
+    pub storage: EntryStorage,
+    /// TTL in seconds for 404s and empty results (1 minute, 0 disables)
+    pub negative_ttl: u64,
+    /// How concurrent identical upstream fetches are shared
+    pub coalesce: Coalescing,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            default_ttl: 600, // 10 minutes
+            max_ttl: 3600,    // 1 hour
+            backend: CacheBackendKind::Kv,
+            memory_capacity: 1000,
+            max_stale: 3600, // 1 hour
+            user_scoped: UserScopedCaching::Partition,
+            cache_authorized: false,
+            ttl_rules: default_ttl_rules(),
+            honor_no_cache: false,
+            storage: EntryStorage::default(),
+            negative_ttl: 60, // 1 minute
+            coalesce: Coalescing::Isolate,
+        }
+    }
+}
+
+impl CacheConfig {
+    /// Read the backend selection from worker vars, falling back to the defaults:
+    /// `CACHE_BACKEND` ("kv", "cache-api", "memory", "none"),
+    /// `CACHE_MEMORY_CAPACITY` (entries kept by the memory backend),
+    /// `CACHE_MAX_STALE` (seconds stale entries may be served, 0 disables),
+    /// `CACHE_USER_SCOPED` ("partition", "bypass"),
+    /// `CACHE_AUTHORIZED_REQUESTS` ("true" to cache requests with `Authorization`),
+    /// `CACHE_HONOR_NO_CACHE` ("true" to honor request `Cache-Control`),
+    /// `CACHE_DEFAULT_TTL` / `CACHE_MAX_TTL` (seconds),
+    /// `CACHE_TTL_RULES` (`/search/*=120,/opinions/*=7200`),
+    /// `CACHE_TTL_POLICY` (JSON, see `TtlPolicyConfig`),
+    /// `CACHE_COMPRESSION` ("gzip", "br", "none"),
+    /// `CACHE_CHUNK_SIZE` (bytes stored per key, at most `KV_VALUE_LIMIT`),
+    /// `CACHE_NEGATIVE_TTL` (seconds 404s and empty results are cached, 0 disables) and
+    /// `CACHE_COALESCE` ("off", "isolate", "durable-object")
+    /// Configured rules are checked before the built-in ones
+    #[cfg(feature = "worker")]
+    pub fn from_env(env: &worker::Env) -> Self {
+        let var = |name: &str| env.var(name).ok().map(|v| v.to_string());
+        let mut config = Self::default();
+        if let Some(backend) = var("CACHE_BACKEND").and_then(|v| CacheBackendKind::parse(&v)) {
+            config.backend = backend;
+        }
+        if let Some(capacity) = var("CACHE_MEMORY_CAPACITY").and_then(|v| v.trim().parse().ok()) {
+            config.memory_capacity = capacity;
+        }
+        if let Some(max_stale) = var("CACHE_MAX_STALE").and_then(|v| v.trim().parse().ok()) {
+            config.max_stale = max_stale;
+        }
+        if let Some(mode) = var("CACHE_USER_SCOPED").and_then(|v| UserScopedCaching::parse(&v)) {
+            config.user_scoped = mode;
+        }
+        if let Some(enabled) = var("CACHE_AUTHORIZED_REQUESTS") {
+            config.cache_authorized = enabled.trim().eq_ignore_ascii_case("true");
+        }
+        if let Some(enabled) = var("CACHE_HONOR_NO_CACHE") {
+            config.honor_no_cache = enabled.trim().eq_ignore_ascii_case("true");
+        }
+        if let Some(ttl) = var("CACHE_DEFAULT_TTL").and_then(|v| v.trim().parse().ok()) {
+            config.default_ttl = ttl;
+        }
+        if let Some(ttl) = var("CACHE_MAX_TTL").and_then(|v| v.trim().parse().ok()) {
+            config.max_ttl = ttl;
+        }
+        if let Some(encoding) = var("CACHE_COMPRESSION").and_then(|v| ContentEncoding::parse(&v)) {
+            config.storage.encoding = encoding;
+        }
+        if let Some(size) = var("CACHE_CHUNK_SIZE").and_then(|v| v.trim().parse::<usize>().ok()) {
+            config.storage.chunk_size = size.clamp(1024, KV_VALUE_LIMIT);
+        }
+        if let Some(ttl) = var("CACHE_NEGATIVE_TTL").and_then(|v| v.trim().parse().ok()) {
+            config.negative_ttl = ttl;
+        }
+        if let Some(mode) = var("CACHE_COALESCE").and_then(|v| Coalescing::parse(&v)) {
+            config.coalesce = mode;
+        }
+        if let Some(rules) = var("CACHE_TTL_RULES") {
+            config.prepend_rules(parse_ttl_rules(&rules));
+        }
+        // Either a JSON object var or a string holding JSON
+        let policy = env
+            .object_var::<TtlPolicyConfig>("CACHE_TTL_POLICY")
+            .ok()
+            .or_else(|| {
+                let json = var("CACHE_TTL_POLICY")?;
+                match TtlPolicyConfig::from_json(&json) {
+                    Ok(policy) => Some(policy),
+                    Err(e) => {
+                        worker::console_log!("Ignoring CACHE_TTL_POLICY: {}", e);
+                        None
+                    }
+                }
+            });
+        if let Some(policy) = policy {
+            config.apply_policy(policy);
+        }
+        config
+    }
+
+    /// Apply a TTL policy on top of this configuration
+    pub fn apply_policy(&mut self, policy: TtlPolicyConfig) {
+        if let Some(ttl) = policy.default_ttl {
+            self.default_ttl = ttl;
+        }
+        if let Some(ttl) = policy.max_ttl {
+            self.max_ttl = ttl;
+        }
+        self.prepend_rules(policy.rules);
+    }
+
+    fn prepend_rules(&mut self, mut rules: Vec<TtlRule>) {
+        rules.append(&mut self.ttl_rules);
+        self.ttl_rules = rules;
+    }
+
+    /// TTL in seconds for an endpoint, capped at `max_ttl`
+    pub fn ttl_for(&self, endpoint: &str) -> u64 {
+        self.ttl_rules
+            .iter()
+            .find(|rule| rule.matches(endpoint))
+            .map_or(self.default_ttl, |rule| rule.ttl)
+            .min(self.max_ttl)
+    }
+
+    /// TTL in seconds for a 404 or empty result from an endpoint
+    /// Never longer than the endpoint's positive TTL; 0 means do not cache
+    pub fn negative_ttl_for(&self, endpoint: &str) -> u64 {
+        self.negative_ttl.min(self.ttl_for(endpoint))
+    }
+}
+
+/// Whether a response body is a list with no results, such as a search that
+/// matched nothing (`{"count": 0, "results": []}`)
+pub fn is_empty_result(body: &str) -> bool {
+    // Empty pages are small; skip parsing anything larger
+    if body.len() > 4096 {
+        return false;
+    }
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(body) else {
+        return false;
+    };
+    let no_results = value
+        .get("results")
+        .and_then(|results| results.as_array())
+        .is_some_and(|results| results.is_empty());
+    let count = value.get("count").and_then(|count| count.as_u64());
+    no_results && count.unwrap_or(0) == 0
+}
+
+/// Determine cache TTL based on endpoint type, using the built-in policy
+/// Worker requests use `CacheConfig::ttl_for` so configured rules apply
+pub fn get_cache_ttl(endpoint: &str) -> u64 {
+    CacheConfig::default().ttl_for(endpoint)
+}
+
+/// Add cache headers to response based on cache status
+/// `shared`: whether shared caches may store the response; per-user responses
+/// are marked private and bypassed responses are not stored at all
+#[cfg(feature = "worker")]
+pub fn add_cache_headers(
+    headers: &mut worker::Headers,
+    cache_ttl: u64,
+    status: CacheStatus,
+    shared: bool,
+) -> worker::Result<()> {
+    headers.set("X-Cache", status.as_str())?;
+    // ETags depend on the representation, which varies with these headers
+    headers.set("Vary", "Accept, Authorization")?;
+
+    let cache_control = if status == CacheStatus::Bypass {
+        "private, no-store".to_string()
+    } else if shared {
+        // Add stale-while-revalidate for better performance
+        format!(
+            "public, max-age={}, s-maxage={}, stale-while-revalidate={}",
+            cache_ttl,
+            cache_ttl,
+            cache_ttl / 2
+        )
+    } else {
+        format!("private, max-age={}", cache_ttl)
+    };
+    headers.set("Cache-Control", &cache_control)?;
+
+    Ok(())
+}
+
+/// Result of looking up a cache entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheLookup {
+    /// Within its TTL
+    Fresh(String),
+    /// Past its TTL but within the stale bound; serve it and refresh
+    Stale(String),
+    /// Cached upstream error (such as a 404) with its status and body
+    Negative { status: u16, body: String },
+    /// Missing or past the hard expiry
+    Miss,
+}
+
+/// Look up a cached response, classifying it as fresh, stale, negative or missing
+/// Compressed and chunked entries are decoded; backend errors and entries
+/// with missing chunks are treated as misses
+pub async fn lookup_entry<C: CacheBackend>(cache: &C, key: &str) -> CacheLookup {
+    let Some(bytes) = cache.get(key).await.ok().flatten() else {
+        return CacheLookup::Miss;
+    };
+    let (header, body) = match StoredEntry::parse(bytes) {
+        Some(StoredEntry::Entry { header, body }) => (header, body),
+        // Legacy format (plain string), return as-is
+        Some(StoredEntry::Legacy(text)) => return CacheLookup::Fresh(text),
+        None => return CacheLookup::Miss,
+    };
+    // Backends expire entries themselves, but check timestamp as backup
+    // Timestamp is in seconds since epoch
+    let age = header.age(now_millis() / 1000);
+    if age > header.ttl.saturating_add(header.max_stale) {
+        // Past the hard expiry, remove from cache
+        let _ = delete_entry(cache, key).await;
+        return CacheLookup::Miss;
+    }
+    let Some(data) = read_body(cache, key, &header, body).await else {
+        return CacheLookup::Miss;
+    };
+    match header.status {
+        // Negative entries are never served stale
+        Some(_) if age > header.ttl => CacheLookup::Miss,
+        Some(status) => CacheLookup::Negative { status, body: data },
+        None if age <= header.ttl => CacheLookup::Fresh(data),
+        None => CacheLookup::Stale(data),
+    }
+}
+
+/// Get a fresh cached response, ignoring stale entries
+pub async fn get_entry<C: CacheBackend>(cache: &C, key: &str) -> Option<String> {
+    match lookup_entry(cache, key).await {
+        CacheLookup::Fresh(data) => Some(data),
+        CacheLookup::Stale(_) | CacheLookup::Negative { .. } | CacheLookup::Miss => None,
+    }
+}
+
+/// Store a response with expiration metadata using the default storage
+/// (gzip above `entry::MIN_COMPRESS_SIZE`, chunked at the KV value limit)
+/// The entry is fresh for `ttl` seconds and may be served stale for
+/// `max_stale` seconds after that
+/// Backend errors are ignored; caching is best effort
+pub async fn put_entry<C: CacheBackend>(
+    cache: &C,
+    key: &str,
+    value: &str,
+    ttl: u64,
+    max_stale: u64,
+) {
+    put_entry_with(cache, key, value, ttl, max_stale, &EntryStorage::default()).await
+}
+
+/// Store a response like `put_entry`, with explicit compression and chunking
+pub async fn put_entry_with<C: CacheBackend>(
+    cache: &C,
+    key: &str,
+    value: &str,
+    ttl: u64,
+    max_stale: u64,
+    storage: &EntryStorage,
+) {
+    if let Ok(encoded) = encode_entry(key, value, ttl, max_stale, storage, None) {
+        // Add 1 minute buffer for expiration
+        store_encoded(cache, key, encoded, ttl + max_stale + 60).await;
+    }
+}
+
+/// Store an upstream error response (such as a 404) for `ttl` seconds
+/// Lookups return it as `CacheLookup::Negative` and never serve it stale
+pub async fn put_negative_entry<C: CacheBackend>(
+    cache: &C,
+    key: &str,
+    status: u16,
+    body: &str,
+    ttl: u64,
+) {
+    let storage = EntryStorage::default();
+    if let Ok(encoded) = encode_entry(key, body, ttl, 0, &storage, Some(status)) {
+        store_encoded(cache, key, encoded, ttl + 60).await;
+    }
+}
+
+async fn store_encoded<C: CacheBackend>(
+    cache: &C,
+    key: &str,
+    encoded: EncodedEntry,
+    backend_ttl: u64,
+) {
+    // Chunks go first so the header never points at missing chunks
+    for (chunk_key, chunk) in &encoded.chunks {
+        if cache.put(chunk_key, chunk, backend_ttl).await.is_err() {
+            return;
+        }
+    }
+    let _ = cache.put(key, &encoded.primary, backend_ttl).await;
+}
+
+/// Remove an entry and any chunks stored with it
+pub async fn delete_entry<C: CacheBackend>(cache: &C, key: &str) -> Result<()> {
+    if let Some(bytes) = cache.get(key).await? {
+        if let Some(StoredEntry::Entry { header, .. }) = StoredEntry::parse(bytes) {
+            for chunk_key in header.chunk_keys(key) {
+                cache.delete(&chunk_key).await?;
+            }
+        }
+    }
+    cache.delete(key).await
+}
+
+/// Cache backend for worker requests, selected by `CacheConfig`
+#[cfg(feature = "worker")]
+#[derive(Clone)]
+pub enum WorkerCache {
+    Kv(KvCache),
+    CacheApi(EdgeCache),
+    Memory(MemoryCache),
+    None(NoopCache),
+}
+
+#[cfg(feature = "worker")]
+impl WorkerCache {
+    /// Build the configured backend
+    /// Falls back to no caching when the `CACHE` KV namespace is not bound
+    pub fn from_config(env: &worker::Env, config: &CacheConfig) -> Self {
+        thread_local! {
+            static ISOLATE_CACHE: std::cell::RefCell<Option<MemoryCache>> =
+                const { std::cell::RefCell::new(None) };
+        }
+
+        match config.backend {
+            CacheBackendKind::Kv => match env.kv("CACHE") {
+                Ok(kv) => Self::Kv(KvCache::new(kv)),
+                Err(_) => Self::None(NoopCache),
+            },
+            CacheBackendKind::CacheApi => Self::CacheApi(EdgeCache::new()),
+            CacheBackendKind::Memory => Self::Memory(ISOLATE_CACHE.with(|cell| {
+                let mut cell = cell.borrow_mut();
+                match cell.as_ref() {
+                    Some(cache) if cache.capacity() == config.memory_capacity => cache.clone(),
+                    _ => cell
+                        .insert(MemoryCache::new(config.memory_capacity))
+                        .clone(),
+                }
+            })),
+            CacheBackendKind::None => Self::None(NoopCache),
+        }
+    }
+
+    /// Build the backend configured by the worker vars
+    pub fn from_env(env: &worker::Env) -> Self {
+        Self::from_config(env, &CacheConfig::from_env(env))
+    }
+}
+
+#[cfg(feature = "worker")]
+impl CacheBackend for WorkerCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match self {
+            Self::Kv(cache) => cache.get(key).await,
+            Self::CacheApi(cache) => cache.get(key).await,
+            Self::Memory(cache) => cache.get(key).await,
+            Self::None(cache) => cache.get(key).await,
+        }
+    }
+
+    async fn put(&self, key: &str, value: &[u8], ttl: u64) -> Result<()> {
+        match self {
+            Self::Kv(cache) => cache.put(key, value, ttl).await,
+            Self::CacheApi(cache) => cache.put(key, value, ttl).await,
+            Self::Memory(cache) => cache.put(key, value, ttl).await,
+            Self::None(cache) => cache.put(key, value, ttl).await,
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match self {
+            Self::Kv(cache) => cache.delete(key).await,
+            Self::CacheApi(cache) => cache.delete(key).await,
+            Self::Memory(cache) => cache.delete(key).await,
+            Self::None(cache) => cache.delete(key).await,
+        }
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        match self {
+            Self::Kv(cache) => cache.list(prefix).await,
+            Self::CacheApi(cache) => cache.list(prefix).await,
+            Self::Memory(cache) => cache.list(prefix).await,
+            Self::None(cache) => cache.list(prefix).await,
+        }
+    }
+}
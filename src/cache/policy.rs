@@ -0,0 +1,163 @@
+//! Per-endpoint TTL policy and request cache directives
+//!
+//! TTLs come from path-pattern rules checked in order; the first match wins and
+//! unmatched endpoints use the default. Every TTL is capped at `max_ttl`.
+//! Patterns are endpoint paths where `*` matches any run of characters, e.g.
+//! `/search/*` or `/opinions/*/`.
+
+use crate::errors::{CourtListenerError, Result};
+use serde::{Deserialize, Serialize};
+
+/// TTL for endpoints matching a path pattern
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TtlRule {
+    pub pattern: String,
+    /// Seconds
+    pub ttl: u64,
+}
+
+impl TtlRule {
+    pub fn new(pattern: impl Into<String>, ttl: u64) -> Self {
+        Self {
+            pattern: pattern.into(),
+            ttl,
+        }
+    }
+
+    /// Whether the rule applies to an endpoint path (any query is ignored)
+    pub fn matches(&self, endpoint: &str) -> bool {
+        let path = endpoint.split('?').next().unwrap_or_default();
+        let path = format!("/{}", path.trim_start_matches('/'));
+        let pattern = format!("/{}", self.pattern.trim_start_matches('/'));
+        glob_match(pattern.as_bytes(), path.as_bytes())
+    }
+}
+
+/// Built-in rules: search results change often, opinions and reference data rarely
+pub fn default_ttl_rules() -> Vec<TtlRule> {
+    vec![
+        // Search results: shorter cache (5 minutes) as they can change frequently
+        TtlRule::new("/search/*", 300),
+        // Dockets and RECAP data: medium cache (15 minutes)
+        TtlRule::new("/dockets/*", 900),
+        TtlRule::new("/docket-entries/*", 900),
+        TtlRule::new("/recap-documents/*", 900),
+        TtlRule::new("/parties/*", 900),
+        TtlRule::new("/attorneys/*", 900),
+        TtlRule::new("/originating-court-information/*", 900),
+        TtlRule::new("/fjc-integrated-database/*", 900),
+        TtlRule::new("/docket-alerts*", 900),
+        // Opinions: longer cache (30 minutes) as they rarely change
+        TtlRule::new("/opinions/*", 1800),
+        TtlRule::new("/clusters/*", 1800),
+        // Courts and the People DB: longest cache (1 hour) as they rarely change
+        TtlRule::new("/courts/*", 3600),
+        TtlRule::new("/people/*", 3600),
+        TtlRule::new("/positions/*", 3600),
+        TtlRule::new("/retention-events/*", 3600),
+        TtlRule::new("/educations/*", 3600),
+        TtlRule::new("/schools/*", 3600),
+        TtlRule::new("/political-affiliations/*", 3600),
+        TtlRule::new("/aba-ratings/*", 3600),
+        TtlRule::new("/sources/*", 3600),
+        // Financial disclosures: filed yearly, so cache as long as reference data
+        TtlRule::new("/financial-disclosures/*", 3600),
+        TtlRule::new("/investments/*", 3600),
+        TtlRule::new("/disclosure-positions/*", 3600),
+        TtlRule::new("/agreements/*", 3600),
+        TtlRule::new("/non-investment-incomes/*", 3600),
+        TtlRule::new("/spouse-incomes/*", 3600),
+        TtlRule::new("/reimbursements/*", 3600),
+        TtlRule::new("/gifts/*", 3600),
+        TtlRule::new("/debts/*", 3600),
+    ]
+}
+
+/// TTL policy as written in the `CACHE_TTL_POLICY` JSON binding
+/// Rules are checked before the built-in ones; omitted fields keep their defaults
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TtlPolicyConfig {
+    #[serde(default)]
+    pub default_ttl: Option<u64>,
+    #[serde(default)]
+    pub max_ttl: Option<u64>,
+    #[serde(default)]
+    pub rules: Vec<TtlRule>,
+}
+
+impl TtlPolicyConfig {
+    /// Parse the JSON form: `{"default_ttl": 600, "max_ttl": 3600,
+    /// "rules": [{"pattern": "/search/*", "ttl": 120}]}`
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| CourtListenerError::InvalidRequest(format!("Invalid TTL policy: {}", e)))
+    }
+}
+
+/// Parse `pattern=ttl` pairs separated by commas, as in `CACHE_TTL_RULES`
+/// (`/search/*=120,/opinions/*=7200`); malformed pairs are skipped
+pub fn parse_ttl_rules(value: &str) -> Vec<TtlRule> {
+    value
+        .split(',')
+        .filter_map(|pair| {
+            let (pattern, ttl) = pair.split_once('=')?;
+            let pattern = pattern.trim();
+            let ttl = ttl.trim().parse().ok()?;
+            (!pattern.is_empty()).then(|| TtlRule::new(pattern, ttl))
+        })
+        .collect()
+}
+
+/// Cache directives from a request's `Cache-Control` / `Pragma` headers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheDirectives {
+    /// Skip cached copies and fetch from upstream (the result is still cached)
+    pub no_cache: bool,
+    /// Neither read nor write the cache
+    pub no_store: bool,
+}
+
+impl CacheDirectives {
+    pub fn parse(cache_control: Option<&str>, pragma: Option<&str>) -> Self {
+        let mut directives = Self::default();
+        for directive in cache_control.unwrap_or_default().split(',') {
+            let (name, value) = directive.split_once('=').unwrap_or((directive, ""));
+            match name.trim().to_ascii_lowercase().as_str() {
+                "no-cache" => directives.no_cache = true,
+                // max-age=0 also asks for a fresh copy
+                "max-age" if value.trim() == "0" => directives.no_cache = true,
+                "no-store" => directives.no_store = true,
+                _ => {}
+            }
+        }
+        // HTTP/1.0 clients send Pragma instead
+        if cache_control.is_none()
+            && pragma.is_some_and(|p| p.trim().eq_ignore_ascii_case("no-cache"))
+        {
+            directives.no_cache = true;
+        }
+        directives
+    }
+}
+
+/// Glob match where `*` matches any run of bytes
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&b| b == b'*')
+}
@@ -0,0 +1,129 @@
+//! Auth-aware cache partitioning
+//!
+//! Public endpoints share one cache. User-scoped endpoints (alerts, tags, ...)
+//! return data for the credential used upstream, so their entries are either
+//! partitioned by a hash of that credential or not cached at all. Requests that
+//! carry their own `Authorization` header bypass the cache unless configured.
+
+use super::CacheConfig;
+
+/// Endpoints whose responses depend on the authenticated user
+const USER_SCOPED_PREFIXES: [&str; 6] = [
+    "alerts/",
+    "docket-alerts/",
+    "tags/",
+    "docket-tags/",
+    "visualizations/",
+    "recap-fetch/",
+];
+
+/// Whether an endpoint's responses are the same for every caller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheScope {
+    Public,
+    User,
+}
+
+impl CacheScope {
+    /// Classify an endpoint path such as `/docket-alerts/12/`
+    /// Any query string or fragment is ignored (`/docket-alerts?page=2`)
+    pub fn of(endpoint: &str) -> Self {
+        let path = endpoint.split(['?', '#']).next().unwrap_or_default();
+        let path = path.trim_start_matches('/');
+        let user_scoped = USER_SCOPED_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix) || path == prefix.trim_end_matches('/'));
+        if user_scoped {
+            Self::User
+        } else {
+            Self::Public
+        }
+    }
+}
+
+/// How user-scoped responses are cached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UserScopedCaching {
+    /// Cache per credential
+    #[default]
+    Partition,
+    /// Never cache
+    Bypass,
+}
+
+impl UserScopedCaching {
+    /// Parse a `CACHE_USER_SCOPED` value: "partition" or "bypass"
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "partition" => Some(Self::Partition),
+            "bypass" | "none" => Some(Self::Bypass),
+            _ => None,
+        }
+    }
+}
+
+/// Where a response may be cached
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CachePartition {
+    /// Shared by all callers
+    Shared,
+    /// Only for callers with the same credential (hash)
+    Private(String),
+    /// Not cached
+    Bypass,
+}
+
+impl CachePartition {
+    /// Decide how to cache a request for `endpoint`
+    /// `caller_authorization` is the request's own `Authorization` header and
+    /// `upstream_credential` the token the worker sends upstream
+    pub fn for_request(
+        endpoint: &str,
+        caller_authorization: Option<&str>,
+        upstream_credential: Option<&str>,
+        config: &CacheConfig,
+    ) -> Self {
+        let caller_authorization = caller_authorization.filter(|auth| !auth.is_empty());
+        if caller_authorization.is_some() && !config.cache_authorized {
+            return Self::Bypass;
+        }
+        let credential = caller_authorization.or(upstream_credential);
+        match (CacheScope::of(endpoint), config.user_scoped) {
+            (CacheScope::User, UserScopedCaching::Bypass) => Self::Bypass,
+            (CacheScope::User, UserScopedCaching::Partition) => {
+                Self::Private(credential_hash(credential))
+            }
+            // Callers with their own credential never share entries
+            (CacheScope::Public, _) if caller_authorization.is_some() => {
+                Self::Private(credential_hash(credential))
+            }
+            (CacheScope::Public, _) => Self::Shared,
+        }
+    }
+
+    /// Cache key within this partition, or None when bypassing the cache
+    pub fn key(&self, key: &str) -> Option<String> {
+        match self {
+            Self::Shared => Some(key.to_string()),
+            Self::Private(hash) => Some(format!("{}|user:{}", key, hash)),
+            Self::Bypass => None,
+        }
+    }
+
+    /// Whether shared caches (CDNs, proxies) may store the response
+    pub fn is_shared(&self) -> bool {
+        matches!(self, Self::Shared)
+    }
+}
+
+/// Short SHA-256 hash of a credential, or "anonymous"
+pub fn credential_hash(credential: Option<&str>) -> String {
+    use sha2::{Digest, Sha256};
+    match credential {
+        Some(credential) if !credential.is_empty() => {
+            let digest = Sha256::digest(credential.as_bytes());
+            digest[..16].iter().map(|b| format!("{:02x}", b)).collect()
+        }
+        _ => "anonymous".to_string(),
+    }
+}
@@ -1,8 +1,12 @@
 //! Error types for the CourtListener Worker
 
+use std::collections::BTreeMap;
 #[cfg(feature = "worker")]
 use worker::Error as WorkerError;
 
+/// Maximum number of characters of an error body or detail kept in responses
+const MAX_ERROR_BODY_LEN: usize = 200;
+
 /// Main error type for the CourtListener Worker
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -20,6 +24,148 @@ pub enum CourtListenerError {
     Validation(String),
     /// Invalid request error
     InvalidRequest(String),
+    /// Non-2xx response from the CourtListener API
+    Api(ApiError),
+    /// Local rate limit exhausted; seconds until a request is allowed
+    RateLimited(u64),
+}
+
+/// Error response returned by the CourtListener API
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiError {
+    /// HTTP status code
+    pub status: u16,
+    /// DRF `detail` message (or the start of a non-JSON body)
+    pub detail: Option<String>,
+    /// DRF field errors, e.g. `{"court": ["Select a valid choice."]}`
+    pub field_errors: BTreeMap<String, Vec<String>>,
+    /// Seconds to wait before retrying, from the `Retry-After` header
+    pub retry_after: Option<u64>,
+}
+
+impl ApiError {
+    /// Build from a response status, body and `Retry-After` header value
+    pub fn from_response(status: u16, body: &str, retry_after: Option<&str>) -> Self {
+        let mut error = Self {
+            status,
+            retry_after: retry_after.and_then(parse_retry_after),
+            ..Self::default()
+        };
+        match serde_json::from_str::<serde_json::Value>(body) {
+            Ok(serde_json::Value::Object(map)) => {
+                for (key, value) in map {
+                    if key == "detail" {
+                        error.detail = Some(error_text(&value));
+                    } else {
+                        error.field_errors.insert(key, error_messages(&value));
+                    }
+                }
+            }
+            Ok(serde_json::Value::Array(values)) => {
+                let messages: Vec<String> = values.iter().map(error_text).collect();
+                error.detail = Some(messages.join("; "));
+            }
+            _ => {
+                let body = body.trim();
+                if !body.is_empty() {
+                    error.detail = Some(truncate_detail(body).to_string());
+                }
+            }
+        }
+        error
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "API returned {}", self.status)?;
+        if let Some(detail) = &self.detail {
+            write!(f, ": {}", detail)?;
+        }
+        for (field, messages) in &self.field_errors {
+            write!(f, "; {}: {}", field, messages.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// First `MAX_ERROR_BODY_LEN` characters of an error text
+/// Cuts on a character boundary, so multi-byte text is never split
+pub fn truncate_detail(text: &str) -> &str {
+    text.char_indices()
+        .nth(MAX_ERROR_BODY_LEN)
+        .map_or(text, |(end, _)| &text[..end])
+}
+
+/// Parse the delta-seconds form of `Retry-After` (HTTP dates are ignored)
+fn parse_retry_after(value: &str) -> Option<u64> {
+    value.trim().parse().ok()
+}
+
+/// Messages of a DRF field error, which may be a list, a string or nested
+fn error_messages(value: &serde_json::Value) -> Vec<String> {
+    match value {
+        serde_json::Value::Array(values) => values.iter().map(error_text).collect(),
+        other => vec![error_text(other)],
+    }
+}
+
+fn error_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Worker errors are not `Clone`, so clones keep only their message
+impl Clone for CourtListenerError {
+    fn clone(&self) -> Self {
+        match self {
+            #[cfg(feature = "worker")]
+            Self::Worker(e) => Self::Worker(WorkerError::RustError(e.to_string())),
+            Self::Json(e) => Self::Json(e.clone()),
+            Self::Http(e) => Self::Http(e.clone()),
+            Self::Cache(e) => Self::Cache(e.clone()),
+            Self::Validation(e) => Self::Validation(e.clone()),
+            Self::InvalidRequest(e) => Self::InvalidRequest(e.clone()),
+            Self::Api(e) => Self::Api(e.clone()),
+            Self::RateLimited(seconds) => Self::RateLimited(*seconds),
+        }
+    }
+}
+
+impl CourtListenerError {
+    /// HTTP status of an API error
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Api(e) => Some(e.status),
+            _ => None,
+        }
+    }
+
+    /// The requested object does not exist (404)
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+
+    /// The request was throttled upstream (429) or by the local rate limiter
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::RateLimited(_)) || self.status() == Some(429)
+    }
+
+    /// The token is missing, invalid or lacks permission (401/403)
+    pub fn is_auth(&self) -> bool {
+        matches!(self.status(), Some(401 | 403))
+    }
+
+    /// Seconds to wait before retrying a throttled request
+    pub fn retry_after(&self) -> Option<u64> {
+        match self {
+            Self::Api(e) => e.retry_after,
+            Self::RateLimited(seconds) => Some(*seconds),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(feature = "worker")]
@@ -35,6 +181,22 @@ impl From<serde_json::Error> for CourtListenerError {
     }
 }
 
+impl From<validator::ValidationErrors> for CourtListenerError {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        let error_msg = errors
+            .field_errors()
+            .iter()
+            .map(|(field, errors)| {
+                let error_details: Vec<String> =
+                    errors.iter().map(|e| format!("{:?}", e.code)).collect();
+                format!("{}: {}", field, error_details.join(", "))
+            })
+            .collect::<Vec<String>>()
+            .join("; ");
+        Self::Validation(error_msg)
+    }
+}
+
 impl std::fmt::Display for CourtListenerError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -45,6 +207,10 @@ impl std::fmt::Display for CourtListenerError {
             Self::Cache(e) => write!(f, "Cache error: {}", e),
             Self::Validation(e) => write!(f, "Validation error: {}", e),
             Self::InvalidRequest(e) => write!(f, "Invalid request: {}", e),
+            Self::Api(e) => write!(f, "{}", e),
+            Self::RateLimited(seconds) => {
+                write!(f, "Rate limit exceeded, retry in {}s", seconds)
+            }
         }
     }
 }
@@ -60,9 +226,16 @@ pub type Result<T> = std::result::Result<T, CourtListenerError>;
 impl CourtListenerError {
     #[allow(dead_code)]
     pub fn to_worker_result<T>(result: Result<T>) -> worker::Result<T> {
-        result.map_err(|e| match e {
+        result.map_err(WorkerError::from)
+    }
+}
+
+#[cfg(feature = "worker")]
+impl From<CourtListenerError> for WorkerError {
+    fn from(err: CourtListenerError) -> Self {
+        match err {
             CourtListenerError::Worker(err) => err,
             other => WorkerError::RustError(other.to_string()),
-        })
+        }
     }
 }
@@ -0,0 +1,238 @@
+//! Cache administration endpoints
+//!
+//! All routes require `Authorization: Bearer <CACHE_ADMIN_TOKEN>`; without the
+//! secret configured they are disabled.
+
+use crate::api::ApiClient;
+use crate::cache::{
+    delete_entry, generate_cache_key, inspect_entry, purge_prefix, resource_prefix, WorkerCache,
+};
+use crate::errors::{ApiError, CourtListenerError};
+use crate::utils::{error_response, json_response};
+use serde::Deserialize;
+use worker::*;
+
+/// Maximum endpoints accepted by one warm request
+const MAX_WARM_ENDPOINTS: usize = 50;
+
+/// Purge request body; exactly one selector is used, checked in this order
+#[derive(Debug, Deserialize)]
+pub struct PurgeRequest {
+    /// Exact cache key
+    pub key: Option<String>,
+    /// Raw key prefix
+    pub prefix: Option<String>,
+    /// Endpoint path such as `/opinions/`, purging every cached variant of it
+    pub endpoint: Option<String>,
+    /// Resource type such as `opinions`, used with `id`
+    pub resource: Option<String>,
+    pub id: Option<serde_json::Value>,
+}
+
+/// Warm request body
+#[derive(Debug, Deserialize)]
+pub struct WarmRequest {
+    /// Endpoints with optional query, such as `/courts/?jurisdiction=F`
+    pub endpoints: Vec<String>,
+}
+
+/// Inspect a cache entry: `?key=<cache key>` or `?endpoint=/courts/&query=...`
+pub async fn inspect_cache_entry(req: &Request, env: &Env) -> Result<Response> {
+    if let Err(e) = authorize_admin(req, env) {
+        return error_response(&e);
+    }
+    let key = match entry_key(req) {
+        Ok(key) => key,
+        Err(e) => return error_response(&e),
+    };
+    match inspect_entry(&WorkerCache::from_env(env), &key).await {
+        Ok(Some(info)) => json_response(&info),
+        Ok(None) => error_response(&admin_error(404, &format!("No cache entry for {}", key))),
+        Err(e) => error_response(&e),
+    }
+}
+
+/// Delete a single cache entry, addressed like `inspect_cache_entry`
+pub async fn delete_cache_entry(req: &Request, env: &Env) -> Result<Response> {
+    if let Err(e) = authorize_admin(req, env) {
+        return error_response(&e);
+    }
+    let key = match entry_key(req) {
+        Ok(key) => key,
+        Err(e) => return error_response(&e),
+    };
+    match delete_entry(&WorkerCache::from_env(env), &key).await {
+        Ok(()) => json_response(&serde_json::json!({ "purged": 1, "key": key })),
+        Err(e) => error_response(&e),
+    }
+}
+
+/// Purge by exact key, key prefix, endpoint or resource id
+pub async fn purge_cache(req: &Request, env: &Env, body: &str) -> Result<Response> {
+    if let Err(e) = authorize_admin(req, env) {
+        return error_response(&e);
+    }
+    let purge: PurgeRequest = match serde_json::from_str(body) {
+        Ok(purge) => purge,
+        Err(e) => {
+            return error_response(&CourtListenerError::InvalidRequest(format!(
+                "Invalid purge request: {}",
+                e
+            )))
+        }
+    };
+
+    let cache = WorkerCache::from_env(env);
+    let result = if let Some(key) = &purge.key {
+        delete_entry(&cache, key).await.map(|()| (key.clone(), 1))
+    } else {
+        let prefix = match (&purge.prefix, &purge.endpoint, &purge.resource, &purge.id) {
+            (Some(prefix), ..) => prefix.clone(),
+            (None, Some(endpoint), ..) => generate_cache_key(endpoint, None),
+            (None, None, Some(resource), Some(id)) => {
+                let id = id
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| id.to_string());
+                resource_prefix(resource, &id)
+            }
+            _ => {
+                return error_response(&CourtListenerError::InvalidRequest(
+                    "Provide one of key, prefix, endpoint or resource and id".to_string(),
+                ))
+            }
+        };
+        if prefix.is_empty() {
+            return error_response(&CourtListenerError::InvalidRequest(
+                "Refusing to purge an empty prefix".to_string(),
+            ));
+        }
+        purge_prefix(&cache, &prefix)
+            .await
+            .map(|purged| (prefix, purged))
+    };
+
+    match result {
+        Ok((target, purged)) => {
+            worker::console_log!("Cache purge: {} ({} entries)", target, purged);
+            json_response(&serde_json::json!({ "purged": purged, "target": target }))
+        }
+        Err(e) => error_response(&e),
+    }
+}
+
+/// Fetch a list of endpoints from upstream and store them in the cache
+pub async fn warm_cache(req: &Request, env: &Env, body: &str) -> Result<Response> {
+    if let Err(e) = authorize_admin(req, env) {
+        return error_response(&e);
+    }
+    let warm: WarmRequest = match serde_json::from_str(body) {
+        Ok(warm) => warm,
+        Err(e) => {
+            return error_response(&CourtListenerError::InvalidRequest(format!(
+                "Invalid warm request: {}",
+                e
+            )))
+        }
+    };
+    if warm.endpoints.len() > MAX_WARM_ENDPOINTS {
+        return error_response(&CourtListenerError::InvalidRequest(format!(
+            "At most {} endpoints can be warmed at once",
+            MAX_WARM_ENDPOINTS
+        )));
+    }
+
+    let mut results = Vec::with_capacity(warm.endpoints.len());
+    for target in &warm.endpoints {
+        let (endpoint, query) = target.split_once('?').unwrap_or((target, ""));
+        if !endpoint.starts_with('/') || endpoint.contains("..") {
+            results.push(serde_json::json!({
+                "endpoint": target,
+                "ok": false,
+                "error": "Endpoint must be an absolute API path",
+            }));
+            continue;
+        }
+        // ApiClient reads the query (and headers) from a request
+        let url = if query.is_empty() {
+            format!("https://courtlistener-worker.internal{}", endpoint)
+        } else {
+            format!(
+                "https://courtlistener-worker.internal{}?{}",
+                endpoint, query
+            )
+        };
+        let fetched = ApiClient::refresh::<serde_json::Value>(
+            env,
+            endpoint,
+            &Request::new(&url, Method::Get)?,
+        )
+        .await;
+        results.push(match fetched.result {
+            Ok(_) => serde_json::json!({
+                "endpoint": target,
+                "ok": true,
+                "attempts": fetched.attempts,
+            }),
+            Err(e) => serde_json::json!({
+                "endpoint": target,
+                "ok": false,
+                "attempts": fetched.attempts,
+                "error": e.to_string(),
+            }),
+        });
+    }
+
+    let warmed = results.iter().filter(|r| r["ok"] == true).count();
+    json_response(&serde_json::json!({ "warmed": warmed, "results": results }))
+}
+
+/// Check the admin bearer token
+fn authorize_admin(req: &Request, env: &Env) -> std::result::Result<(), CourtListenerError> {
+    let Ok(expected) = env.secret("CACHE_ADMIN_TOKEN").map(|s| s.to_string()) else {
+        return Err(admin_error(403, "Cache administration is not configured"));
+    };
+    let provided = req.headers().get("Authorization").ok().flatten();
+    let token = provided
+        .as_deref()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or_default();
+    if expected.is_empty() || !constant_time_eq(token.as_bytes(), expected.as_bytes()) {
+        return Err(admin_error(401, "Invalid or missing admin token"));
+    }
+    Ok(())
+}
+
+/// Cache key from `?key=` or `?endpoint=&query=`
+fn entry_key(req: &Request) -> std::result::Result<String, CourtListenerError> {
+    let url = req
+        .url()
+        .map_err(|e| CourtListenerError::InvalidRequest(e.to_string()))?;
+    let param = |name: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.into_owned())
+    };
+    if let Some(key) = param("key") {
+        return Ok(key);
+    }
+    match param("endpoint") {
+        Some(endpoint) => Ok(generate_cache_key(&endpoint, param("query").as_deref())),
+        None => Err(CourtListenerError::InvalidRequest(
+            "Provide a key or endpoint parameter".to_string(),
+        )),
+    }
+}
+
+fn admin_error(status: u16, detail: &str) -> CourtListenerError {
+    CourtListenerError::Api(ApiError {
+        status,
+        detail: Some(detail.to_string()),
+        ..Default::default()
+    })
+}
+
+/// Compare secrets without short-circuiting on the first differing byte
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
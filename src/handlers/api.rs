@@ -1,14 +1,21 @@
 //! API endpoint handlers for CourtListener resources
 
+use crate::api::audio;
+use crate::api::rate_limit::acquire_for_env;
 use crate::api::ApiClient;
 use crate::config::{get_api_base_url, get_cors_origins};
-use crate::utils::{json_response, sanitize_error};
+use crate::errors::CourtListenerError;
+use crate::query::search_type_of;
+use crate::types::{SearchResponse, SearchType};
+use crate::utils::{
+    error_response, fetched_json_result, json_result, sanitize_error, upstream_error,
+};
 use worker::*;
 
 /// Fetch API root - lists all available APIs
-pub async fn fetch_api_root(env: &Env, req: &Request) -> Result<Response> {
-    let root: serde_json::Value = ApiClient::fetch_json(env, "/", req).await?;
-    json_response(&root)
+pub async fn fetch_api_root(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let root = ApiClient::fetch_with_context::<serde_json::Value>(env, ctx, "/", req).await;
+    fetched_json_result(req, root)
 }
 
 /// Handle OPTIONS requests for API discovery (returns API metadata)
@@ -52,7 +59,7 @@ pub async fn fetch_api_options(env: &Env, req: &Request) -> Result<Response> {
     headers.set("Access-Control-Allow-Methods", "GET, OPTIONS")?;
     headers.set(
         "Access-Control-Allow-Headers",
-        "Content-Type, Authorization",
+        "Content-Type, Authorization, Cache-Control",
     )?;
     headers.set("Content-Type", "application/json")?;
     Ok(response)
@@ -63,17 +70,18 @@ pub async fn fetch_api_options(env: &Env, req: &Request) -> Result<Response> {
 /// Fetch courts from CourtListener API
 /// Supports all query parameters: filtering, ordering, field selection, pagination
 /// Examples: ?court__jurisdiction=F&order_by=-date_modified&fields=id,name
-pub async fn fetch_courts(env: &Env, req: &Request) -> Result<Response> {
-    let courts: crate::CourtsResponse = ApiClient::fetch_json(env, "/courts/", req).await?;
-    json_response(&courts)
+pub async fn fetch_courts(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let courts =
+        ApiClient::fetch_with_context::<crate::CourtsResponse>(env, ctx, "/courts/", req).await;
+    fetched_json_result(req, courts)
 }
 
 /// Fetch a specific court by ID
 /// Supports query parameters for field selection: ?fields=id,name,full_name
-pub async fn fetch_court(id: &str, env: &Env, req: &Request) -> Result<Response> {
-    let court: crate::ApiCourt =
-        ApiClient::fetch_json(env, &format!("/courts/{}/", id), req).await?;
-    json_response(&court)
+pub async fn fetch_court(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/courts/{}/", id);
+    let court = ApiClient::fetch_with_context::<crate::ApiCourt>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, court)
 }
 
 // --- Opinions & Clusters ---
@@ -81,26 +89,409 @@ pub async fn fetch_court(id: &str, env: &Env, req: &Request) -> Result<Response>
 /// Fetch opinions from CourtListener API
 /// Supports filtering, ordering, field selection, pagination
 /// Examples: ?cluster__docket__court=scotus&order_by=-date_filed&fields=id,cluster_id
-pub async fn fetch_opinions(env: &Env, req: &Request) -> Result<Response> {
-    let opinions: crate::OpinionsResponse = ApiClient::fetch_json(env, "/opinions/", req).await?;
-    json_response(&opinions)
+pub async fn fetch_opinions(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let opinions =
+        ApiClient::fetch_with_context::<crate::OpinionsResponse>(env, ctx, "/opinions/", req).await;
+    fetched_json_result(req, opinions)
 }
 
 /// Fetch opinion clusters from CourtListener API
 /// Supports filtering, ordering, field selection, pagination
-pub async fn fetch_opinion_clusters(env: &Env, req: &Request) -> Result<Response> {
-    let clusters: crate::OpinionClustersResponse =
-        ApiClient::fetch_json(env, "/clusters/", req).await?;
-    json_response(&clusters)
+pub async fn fetch_opinion_clusters(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let clusters = ApiClient::fetch_with_context::<crate::OpinionClustersResponse>(
+        env,
+        ctx,
+        "/clusters/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, clusters)
 }
 
 // --- People ---
 
 /// Fetch people from CourtListener API
 /// Supports filtering, ordering, field selection, pagination
-pub async fn fetch_people(env: &Env, req: &Request) -> Result<Response> {
-    let people: crate::PeopleResponse = ApiClient::fetch_json(env, "/people/", req).await?;
-    json_response(&people)
+pub async fn fetch_people(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let people =
+        ApiClient::fetch_with_context::<crate::PeopleResponse>(env, ctx, "/people/", req).await;
+    fetched_json_result(req, people)
+}
+
+/// Fetch a specific person by ID, with nested positions, educations,
+/// political affiliations, ABA ratings and sources
+pub async fn fetch_person(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/people/{}/", id);
+    let person = ApiClient::fetch_with_context::<crate::ApiPerson>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, person)
+}
+
+/// Fetch positions from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?person=1213&court=scotus&order_by=-date_start
+pub async fn fetch_positions(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let positions =
+        ApiClient::fetch_with_context::<crate::PositionsResponse>(env, ctx, "/positions/", req)
+            .await;
+    fetched_json_result(req, positions)
+}
+
+/// Fetch a specific position by ID
+pub async fn fetch_position(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/positions/{}/", id);
+    let position = ApiClient::fetch_with_context::<crate::Position>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, position)
+}
+
+/// Fetch retention events from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_retention_events(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let retention_events = ApiClient::fetch_with_context::<crate::RetentionEventsResponse>(
+        env,
+        ctx,
+        "/retention-events/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, retention_events)
+}
+
+/// Fetch a specific retention event by ID
+pub async fn fetch_retention_event(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/retention-events/{}/", id);
+    let retention_event =
+        ApiClient::fetch_with_context::<crate::RetentionEvent>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, retention_event)
+}
+
+/// Fetch educations from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?person=1213&school__name__icontains=harvard
+pub async fn fetch_educations(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let educations =
+        ApiClient::fetch_with_context::<crate::EducationsResponse>(env, ctx, "/educations/", req)
+            .await;
+    fetched_json_result(req, educations)
+}
+
+/// Fetch a specific education by ID
+pub async fn fetch_education(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/educations/{}/", id);
+    let education =
+        ApiClient::fetch_with_context::<crate::Education>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, education)
+}
+
+/// Fetch schools from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_schools(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let schools =
+        ApiClient::fetch_with_context::<crate::SchoolsResponse>(env, ctx, "/schools/", req).await;
+    fetched_json_result(req, schools)
+}
+
+/// Fetch a specific school by ID
+pub async fn fetch_school(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/schools/{}/", id);
+    let school = ApiClient::fetch_with_context::<crate::School>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, school)
+}
+
+/// Fetch political affiliations from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_political_affiliations(
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let political_affiliations = ApiClient::fetch_with_context::<
+        crate::PoliticalAffiliationsResponse,
+    >(env, ctx, "/political-affiliations/", req)
+    .await;
+    fetched_json_result(req, political_affiliations)
+}
+
+/// Fetch a specific political affiliation by ID
+pub async fn fetch_political_affiliation(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/political-affiliations/{}/", id);
+    let political_affiliation =
+        ApiClient::fetch_with_context::<crate::PoliticalAffiliation>(env, ctx, &endpoint, req)
+            .await;
+    fetched_json_result(req, political_affiliation)
+}
+
+/// Fetch ABA ratings from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_aba_ratings(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let aba_ratings =
+        ApiClient::fetch_with_context::<crate::AbaRatingsResponse>(env, ctx, "/aba-ratings/", req)
+            .await;
+    fetched_json_result(req, aba_ratings)
+}
+
+/// Fetch a specific ABA rating by ID
+pub async fn fetch_aba_rating(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/aba-ratings/{}/", id);
+    let aba_rating =
+        ApiClient::fetch_with_context::<crate::AbaRating>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, aba_rating)
+}
+
+/// Fetch sources from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_sources(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let sources =
+        ApiClient::fetch_with_context::<crate::SourcesResponse>(env, ctx, "/sources/", req).await;
+    fetched_json_result(req, sources)
+}
+
+/// Fetch a specific source by ID
+pub async fn fetch_source(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/sources/{}/", id);
+    let source = ApiClient::fetch_with_context::<crate::Source>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, source)
+}
+
+// --- Financial Disclosures ---
+
+/// Fetch financial disclosures from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?person=1213&year=2020
+pub async fn fetch_financial_disclosures(
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let financial_disclosures =
+        ApiClient::fetch_with_context::<crate::FinancialDisclosuresResponse>(
+            env,
+            ctx,
+            "/financial-disclosures/",
+            req,
+        )
+        .await;
+    fetched_json_result(req, financial_disclosures)
+}
+
+/// Fetch a specific financial disclosure by ID
+pub async fn fetch_financial_disclosure(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/financial-disclosures/{}/", id);
+    let financial_disclosure =
+        ApiClient::fetch_with_context::<crate::FinancialDisclosure>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, financial_disclosure)
+}
+
+/// Fetch investments from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?financial_disclosure__person=1213&gross_value_code=P1
+pub async fn fetch_investments(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let investments =
+        ApiClient::fetch_with_context::<crate::InvestmentsResponse>(env, ctx, "/investments/", req)
+            .await;
+    fetched_json_result(req, investments)
+}
+
+/// Fetch a specific investment by ID
+pub async fn fetch_investment(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/investments/{}/", id);
+    let investment =
+        ApiClient::fetch_with_context::<crate::Investment>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, investment)
+}
+
+/// Fetch disclosure positions from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_disclosure_positions(
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let disclosure_positions = ApiClient::fetch_with_context::<crate::DisclosurePositionsResponse>(
+        env,
+        ctx,
+        "/disclosure-positions/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, disclosure_positions)
+}
+
+/// Fetch a specific disclosure position by ID
+pub async fn fetch_disclosure_position(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/disclosure-positions/{}/", id);
+    let disclosure_position =
+        ApiClient::fetch_with_context::<crate::DisclosurePosition>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, disclosure_position)
+}
+
+/// Fetch agreements from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_agreements(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let agreements =
+        ApiClient::fetch_with_context::<crate::AgreementsResponse>(env, ctx, "/agreements/", req)
+            .await;
+    fetched_json_result(req, agreements)
+}
+
+/// Fetch a specific agreement by ID
+pub async fn fetch_agreement(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/agreements/{}/", id);
+    let agreement =
+        ApiClient::fetch_with_context::<crate::Agreement>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, agreement)
+}
+
+/// Fetch non-investment incomes from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_non_investment_incomes(
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let non_investment_incomes =
+        ApiClient::fetch_with_context::<crate::NonInvestmentIncomesResponse>(
+            env,
+            ctx,
+            "/non-investment-incomes/",
+            req,
+        )
+        .await;
+    fetched_json_result(req, non_investment_incomes)
+}
+
+/// Fetch a specific non-investment income by ID
+pub async fn fetch_non_investment_income(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/non-investment-incomes/{}/", id);
+    let non_investment_income =
+        ApiClient::fetch_with_context::<crate::NonInvestmentIncome>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, non_investment_income)
+}
+
+/// Fetch spouse incomes from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_spouse_incomes(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let spouse_incomes = ApiClient::fetch_with_context::<crate::SpouseIncomesResponse>(
+        env,
+        ctx,
+        "/spouse-incomes/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, spouse_incomes)
+}
+
+/// Fetch a specific spouse income by ID
+pub async fn fetch_spouse_income(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/spouse-incomes/{}/", id);
+    let spouse_income =
+        ApiClient::fetch_with_context::<crate::SpouseIncome>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, spouse_income)
+}
+
+/// Fetch reimbursements from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_reimbursements(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let reimbursements = ApiClient::fetch_with_context::<crate::ReimbursementsResponse>(
+        env,
+        ctx,
+        "/reimbursements/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, reimbursements)
+}
+
+/// Fetch a specific reimbursement by ID
+pub async fn fetch_reimbursement(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/reimbursements/{}/", id);
+    let reimbursement =
+        ApiClient::fetch_with_context::<crate::Reimbursement>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, reimbursement)
+}
+
+/// Fetch gifts from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_gifts(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let gifts =
+        ApiClient::fetch_with_context::<crate::GiftsResponse>(env, ctx, "/gifts/", req).await;
+    fetched_json_result(req, gifts)
+}
+
+/// Fetch a specific gift by ID
+pub async fn fetch_gift(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/gifts/{}/", id);
+    let gift = ApiClient::fetch_with_context::<crate::Gift>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, gift)
+}
+
+/// Fetch debts from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_debts(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let debts =
+        ApiClient::fetch_with_context::<crate::DebtsResponse>(env, ctx, "/debts/", req).await;
+    fetched_json_result(req, debts)
+}
+
+/// Fetch a specific debt by ID
+pub async fn fetch_debt(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/debts/{}/", id);
+    let debt = ApiClient::fetch_with_context::<crate::Debt>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, debt)
 }
 
 // --- Dockets ---
@@ -108,9 +499,164 @@ pub async fn fetch_people(env: &Env, req: &Request) -> Result<Response> {
 /// Fetch dockets from CourtListener API
 /// Supports filtering, ordering, field selection, pagination
 /// Examples: ?court=scotus&id__range=500,1000&order_by=-date_modified
-pub async fn fetch_dockets(env: &Env, req: &Request) -> Result<Response> {
-    let dockets: crate::DocketsResponse = ApiClient::fetch_json(env, "/dockets/", req).await?;
-    json_response(&dockets)
+pub async fn fetch_dockets(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let dockets =
+        ApiClient::fetch_with_context::<crate::DocketsResponse>(env, ctx, "/dockets/", req).await;
+    fetched_json_result(req, dockets)
+}
+
+// --- RECAP (PACER) ---
+
+/// Fetch docket entries from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?docket=4214664&order_by=entry_number
+pub async fn fetch_docket_entries(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let docket_entries = ApiClient::fetch_with_context::<crate::DocketEntriesResponse>(
+        env,
+        ctx,
+        "/docket-entries/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, docket_entries)
+}
+
+/// Fetch a specific docket entry by ID
+pub async fn fetch_docket_entry(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/docket-entries/{}/", id);
+    let entry = ApiClient::fetch_with_context::<crate::DocketEntry>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, entry)
+}
+
+/// Fetch RECAP documents from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?docket_entry__docket=4214664&is_available=true
+pub async fn fetch_recap_documents(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let recap_documents = ApiClient::fetch_with_context::<crate::RecapDocumentsResponse>(
+        env,
+        ctx,
+        "/recap-documents/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, recap_documents)
+}
+
+/// Fetch a specific RECAP document by ID
+pub async fn fetch_recap_document(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/recap-documents/{}/", id);
+    let document =
+        ApiClient::fetch_with_context::<crate::RecapDocument>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, document)
+}
+
+/// Fetch parties from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?docket=4214664&filter_nested_results=true
+pub async fn fetch_parties(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let parties =
+        ApiClient::fetch_with_context::<crate::PartiesResponse>(env, ctx, "/parties/", req).await;
+    fetched_json_result(req, parties)
+}
+
+/// Fetch a specific party by ID
+pub async fn fetch_party(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/parties/{}/", id);
+    let party = ApiClient::fetch_with_context::<crate::Party>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, party)
+}
+
+/// Fetch attorneys from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?docket=4214664&filter_nested_results=true
+pub async fn fetch_attorneys(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let attorneys =
+        ApiClient::fetch_with_context::<crate::AttorneysResponse>(env, ctx, "/attorneys/", req)
+            .await;
+    fetched_json_result(req, attorneys)
+}
+
+/// Fetch a specific attorney by ID
+pub async fn fetch_attorney(id: &str, env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let endpoint = format!("/attorneys/{}/", id);
+    let attorney = ApiClient::fetch_with_context::<crate::Attorney>(env, ctx, &endpoint, req).await;
+    fetched_json_result(req, attorney)
+}
+
+/// Fetch originating court information from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+pub async fn fetch_originating_court_information(
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let info = ApiClient::fetch_with_context::<crate::OriginatingCourtInformationResponse>(
+        env,
+        ctx,
+        "/originating-court-information/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, info)
+}
+
+/// Fetch originating court information by ID
+pub async fn fetch_originating_court_information_by_id(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/originating-court-information/{}/", id);
+    let info = ApiClient::fetch_with_context::<crate::OriginatingCourtInformation>(
+        env, ctx, &endpoint, req,
+    )
+    .await;
+    fetched_json_result(req, info)
+}
+
+// --- FJC Integrated Database ---
+
+/// Fetch FJC Integrated Database (IDB) records from CourtListener API
+/// Supports filtering, ordering, field selection, pagination
+/// Examples: ?district=dcd&nature_of_suit=440&order_by=-date_filed
+pub async fn fetch_fjc_integrated_database(
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let records = ApiClient::fetch_with_context::<crate::FjcIntegratedDatabaseResponse>(
+        env,
+        ctx,
+        "/fjc-integrated-database/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, records)
+}
+
+/// Fetch a specific IDB record by ID
+pub async fn fetch_fjc_integrated_database_record(
+    id: &str,
+    env: &Env,
+    ctx: &Context,
+    req: &Request,
+) -> Result<Response> {
+    let endpoint = format!("/fjc-integrated-database/{}/", id);
+    let record =
+        ApiClient::fetch_with_context::<crate::FjcIntegratedDatabase>(env, ctx, &endpoint, req)
+            .await;
+    fetched_json_result(req, record)
 }
 
 // --- Search ---
@@ -137,10 +683,22 @@ pub async fn fetch_dockets(env: &Env, req: &Request) -> Result<Response> {
 /// - Result counts for type=d and type=r have ±6% error if over 2000 results
 /// - When highlighting disabled, snippet shows first 500 characters
 /// - Snippet field only responds to q parameter and only displays Opinion text content
-pub async fn fetch_search(env: &Env, req: &Request) -> Result<Response> {
-    // Use generic JSON value since search results vary
-    let results: serde_json::Value = ApiClient::fetch_json(env, "/search/", req).await?;
-    json_response(&results)
+pub async fn fetch_search(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let search_type = match request_search_type(req)? {
+        Ok(search_type) => search_type,
+        Err(e) => return error_response(&e),
+    };
+    // Results vary by type, so parse them into the matching result struct
+    let results = ApiClient::fetch_with_context::<serde_json::Value>(env, ctx, "/search/", req)
+        .await
+        .map(|result| result.and_then(|value| Ok(SearchResponse::from_value(search_type, value)?)));
+    fetched_json_result(req, results)
+}
+
+/// Search type requested by the `type` query parameter (case law by default)
+fn request_search_type(req: &Request) -> Result<crate::errors::Result<SearchType>> {
+    let url = req.url()?;
+    Ok(search_type_of(url.query().unwrap_or_default()))
 }
 
 /// Fetch search results from CourtListener API (POST)
@@ -158,6 +716,10 @@ pub async fn fetch_search(env: &Env, req: &Request) -> Result<Response> {
 /// - Embeddings should be calculated using CourtListener's Inception microservice
 /// - Use fine-tuned model for proper embedding calculation
 pub async fn fetch_search_post(env: &Env, req: &Request, body: &str) -> Result<Response> {
+    let search_type = match request_search_type(req)? {
+        Ok(search_type) => search_type,
+        Err(e) => return error_response(&e),
+    };
     let api_base = get_api_base_url();
     let url = req.url()?;
 
@@ -199,48 +761,54 @@ pub async fn fetch_search_post(env: &Env, req: &Request, body: &str) -> Result<R
         ..Default::default()
     };
 
+    if let Err(e) = acquire_for_env(env).await {
+        return error_response(&e);
+    }
     let api_req = Request::new_with_init(&api_url, &init)?;
     let mut resp = Fetch::Request(api_req).send().await?;
 
     // Check HTTP status
     let status = resp.status_code();
     if !(200..300).contains(&status) {
-        let text = resp.text().await.unwrap_or_default();
-        return Err(worker::Error::RustError(format!(
-            "API returned {}: {}",
-            status,
-            sanitize_error(&text)
-        )));
+        return error_response(&upstream_error(&mut resp).await);
     }
 
     let text = resp.text().await?;
-    let results: serde_json::Value = serde_json::from_str(&text)
-        .map_err(|e| worker::Error::RustError(format!("Failed to parse search response: {}", e)))?;
-
-    json_response(&results)
+    let results = SearchResponse::from_json(search_type, &text)
+        .map_err(|e| CourtListenerError::Json(format!("Failed to parse search response: {}", e)));
+    json_result(results)
 }
 
 // --- Citations ---
 
 /// Fetch citations from CourtListener API
 /// Supports filtering, ordering, field selection, pagination
-pub async fn fetch_citations(env: &Env, req: &Request) -> Result<Response> {
-    let citations: crate::CitationsResponse =
-        ApiClient::fetch_json(env, "/opinions-cited/", req).await?;
-    json_response(&citations)
+pub async fn fetch_citations(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let citations = ApiClient::fetch_with_context::<crate::CitationsResponse>(
+        env,
+        ctx,
+        "/opinions-cited/",
+        req,
+    )
+    .await;
+    fetched_json_result(req, citations)
 }
 
 // --- Audio ---
 
 /// Fetch audio from CourtListener API
 /// Supports filtering, ordering, field selection, pagination
-pub async fn fetch_audio(env: &Env, req: &Request) -> Result<Response> {
-    let audio: crate::AudioResponse = ApiClient::fetch_json(env, "/audio/", req).await?;
-    json_response(&audio)
+pub async fn fetch_audio(env: &Env, ctx: &Context, req: &Request) -> Result<Response> {
+    let audio =
+        ApiClient::fetch_with_context::<crate::AudioResponse>(env, ctx, "/audio/", req).await;
+    fetched_json_result(req, audio)
 }
 
 /// Stream audio file from CourtListener
-/// Supports streaming large audio files efficiently using Cloudflare Workers streaming
+/// The origin body is passed through without buffering. A single byte `Range`
+/// (with `If-Range`) is forwarded, so partial requests get
+/// `206 Partial Content` (or `416`), which are not publicly cacheable;
+/// `HEAD` requests return the headers only
 ///
 /// Query parameters:
 /// - url: Direct URL to audio file (download_url or local_path_mp3 from audio API)
@@ -248,33 +816,11 @@ pub async fn fetch_audio(env: &Env, req: &Request) -> Result<Response> {
 ///
 /// Example:
 /// curl "http://localhost:8787/api/audio/stream?url=https://www.courtlistener.com/media/audio/..."
+/// curl -H "Range: bytes=0-1023" "http://localhost:8787/api/audio/stream?id=12345"
 pub async fn stream_audio_file(req: &Request, env: &Env) -> Result<Response> {
-    let url = req.url()?;
-
-    // Get audio file URL from query parameters
-    let audio_url = if let Some(url_param) = url.query_pairs().find(|(k, _)| k == "url") {
-        url_param.1.to_string()
-    } else if let Some(id_param) = url.query_pairs().find(|(k, _)| k == "id") {
-        // Fetch audio metadata first to get local_path_mp3
-        let audio_id = id_param.1.to_string();
-        let audio: serde_json::Value =
-            ApiClient::fetch_json(env, &format!("/audio/{}/", audio_id), req).await?;
-
-        // Try local_path_mp3 first (enhanced version), fall back to download_url
-        if let Some(local_path) = audio.get("local_path_mp3").and_then(|v| v.as_str()) {
-            if local_path.starts_with("http") {
-                local_path.to_string()
-            } else {
-                // Relative path, construct full URL
-                format!("https://www.courtlistener.com{}", local_path)
-            }
-        } else if let Some(download_url) = audio.get("download_url").and_then(|v| v.as_str()) {
-            download_url.to_string()
-        } else {
-            return Response::error("Audio file URL not found in metadata", 404);
-        }
-    } else {
-        return Response::error("Missing 'url' or 'id' parameter", 400);
+    let audio_url = match resolve_audio_url(req, env).await? {
+        Ok(audio_url) => audio_url,
+        Err(response) => return Ok(response),
     };
 
     // Security: Validate URL is from CourtListener or archive.org
@@ -282,18 +828,27 @@ pub async fn stream_audio_file(req: &Request, env: &Env) -> Result<Response> {
         return Response::error("Invalid audio URL domain", 400);
     }
 
+    let head = req.method() == Method::Head;
+
     // Create request to fetch audio file
-    let mut audio_req = Request::new(&audio_url, Method::Get)?;
-    audio_req.headers_mut()?.set(
+    let method = if head { Method::Head } else { Method::Get };
+    let mut audio_req = Request::new(&audio_url, method)?;
+    let upstream_headers = audio_req.headers_mut()?;
+    upstream_headers.set(
         "User-Agent",
         &format!("courtlistener-worker/{}", env!("CARGO_PKG_VERSION")),
     )?;
+    let forwarded = audio::forwarded_headers(|name| req.headers().get(name).ok().flatten());
+    for (name, value) in forwarded {
+        upstream_headers.set(name, &value)?;
+    }
 
-    // Fetch the audio file - Cloudflare Workers automatically streams large responses
+    // Fetch the audio file; the body stays a stream
     let mut resp = Fetch::Request(audio_req).send().await?;
 
+    // 206 for satisfied ranges, 416 for unsatisfiable ones
     let status = resp.status_code();
-    if !(200..300).contains(&status) {
+    if !(200..300).contains(&status) && status != 416 {
         let text = resp.text().await.unwrap_or_default();
         return Err(worker::Error::RustError(format!(
             "Failed to fetch audio file: {} - {}",
@@ -302,24 +857,27 @@ pub async fn stream_audio_file(req: &Request, env: &Env) -> Result<Response> {
         )));
     }
 
-    // Get response headers
+    let headers = Headers::new();
     let content_type = match resp.headers().get("Content-Type") {
         Ok(Some(val)) => val,
         _ => "audio/mpeg".to_string(),
     };
-    let content_length = resp.headers().get("Content-Length").ok().flatten();
-
-    // Create streaming response by reading the body and creating a new response
-    let body = resp.bytes().await?;
-    let mut response = Response::from_bytes(body)?;
-    let headers = response.headers_mut();
     headers.set("Content-Type", &content_type)?;
-    headers.set("Access-Control-Allow-Origin", &get_cors_origins())?;
-    headers.set("Cache-Control", "public, max-age=86400")?; // Cache for 24 hours
-
-    if let Some(len) = content_length {
-        headers.set("Content-Length", &len)?;
+    for name in audio::PASSTHROUGH_HEADERS {
+        if let Some(value) = resp.headers().get(name)? {
+            headers.set(name, &value)?;
+        }
+    }
+    if !headers.has("Accept-Ranges")? {
+        headers.set("Accept-Ranges", "bytes")?;
     }
+    headers.set("Access-Control-Allow-Origin", &get_cors_origins())?;
+    headers.set(
+        "Access-Control-Expose-Headers",
+        "Content-Length, Content-Range, Accept-Ranges, ETag, Last-Modified",
+    )?;
+    // Only full responses are shared; partial ones are per range
+    headers.set("Cache-Control", audio::cache_control(status))?;
 
     // Set Content-Disposition for file download
     if let Some(filename) = audio_url.split('/').next_back() {
@@ -329,5 +887,57 @@ pub async fn stream_audio_file(req: &Request, env: &Env) -> Result<Response> {
         )?;
     }
 
-    Ok(response)
+    let response = ResponseBuilder::new()
+        .with_status(status)
+        .with_headers(headers);
+    if head {
+        return Ok(response.empty());
+    }
+    let (_, body) = resp.into_parts();
+    Ok(response.body(body))
+}
+
+/// Audio file URL from the `url` parameter, or from the metadata of the audio
+/// named by `id`; the inner `Err` is a response to return as-is
+async fn resolve_audio_url(
+    req: &Request,
+    env: &Env,
+) -> Result<std::result::Result<String, Response>> {
+    let url = req.url()?;
+
+    // Get audio file URL from query parameters
+    if let Some(url_param) = url.query_pairs().find(|(k, _)| k == "url") {
+        return Ok(Ok(url_param.1.to_string()));
+    }
+    let Some(id_param) = url.query_pairs().find(|(k, _)| k == "id") else {
+        return Ok(Err(Response::error(
+            "Missing 'url' or 'id' parameter",
+            400,
+        )?));
+    };
+
+    // Fetch audio metadata first to get local_path_mp3
+    let audio_id = id_param.1.to_string();
+    let audio: serde_json::Value =
+        match ApiClient::fetch_json(env, &format!("/audio/{}/", audio_id), req).await {
+            Ok(audio) => audio,
+            Err(e) => return Ok(Err(error_response(&e)?)),
+        };
+
+    // Try local_path_mp3 first (enhanced version), fall back to download_url
+    if let Some(local_path) = audio.get("local_path_mp3").and_then(|v| v.as_str()) {
+        if local_path.starts_with("http") {
+            Ok(Ok(local_path.to_string()))
+        } else {
+            // Relative path, construct full URL
+            Ok(Ok(format!("https://www.courtlistener.com{}", local_path)))
+        }
+    } else if let Some(download_url) = audio.get("download_url").and_then(|v| v.as_str()) {
+        Ok(Ok(download_url.to_string()))
+    } else {
+        Ok(Err(Response::error(
+            "Audio file URL not found in metadata",
+            404,
+        )?))
+    }
 }
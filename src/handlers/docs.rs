@@ -286,7 +286,52 @@ fn discover_our_endpoints() -> std::collections::HashSet<String> {
         "/api/opinions",
         "/api/clusters",
         "/api/people",
+        "/api/people/:id",
+        "/api/positions",
+        "/api/positions/:id",
+        "/api/retention-events",
+        "/api/retention-events/:id",
+        "/api/educations",
+        "/api/educations/:id",
+        "/api/schools",
+        "/api/schools/:id",
+        "/api/political-affiliations",
+        "/api/political-affiliations/:id",
+        "/api/aba-ratings",
+        "/api/aba-ratings/:id",
+        "/api/sources",
+        "/api/sources/:id",
+        "/api/financial-disclosures",
+        "/api/financial-disclosures/:id",
+        "/api/investments",
+        "/api/investments/:id",
+        "/api/disclosure-positions",
+        "/api/disclosure-positions/:id",
+        "/api/agreements",
+        "/api/agreements/:id",
+        "/api/non-investment-incomes",
+        "/api/non-investment-incomes/:id",
+        "/api/spouse-incomes",
+        "/api/spouse-incomes/:id",
+        "/api/reimbursements",
+        "/api/reimbursements/:id",
+        "/api/gifts",
+        "/api/gifts/:id",
+        "/api/debts",
+        "/api/debts/:id",
         "/api/dockets",
+        "/api/docket-entries",
+        "/api/docket-entries/:id",
+        "/api/recap-documents",
+        "/api/recap-documents/:id",
+        "/api/parties",
+        "/api/parties/:id",
+        "/api/attorneys",
+        "/api/attorneys/:id",
+        "/api/originating-court-information",
+        "/api/originating-court-information/:id",
+        "/api/fjc-integrated-database",
+        "/api/fjc-integrated-database/:id",
         "/api/search",
         "/api/citations",
         "/api/audio",
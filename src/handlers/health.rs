@@ -1,10 +1,12 @@
 //! Health check and status endpoints
 
+use crate::api::rate_limit::WorkerRateLimiter;
+use crate::cache::CacheConfig;
 use crate::config::{API_VERSION, API_BASE_URL};
 use worker::*;
 
 /// Enhanced health check endpoint
-/// Returns JSON with worker status, API version, and cache availability
+/// Returns JSON with worker status, API version, cache availability and rate limit budget
 pub async fn health_check(env: &Env) -> Result<Response> {
     let mut status = serde_json::json!({
         "status": "healthy",
@@ -19,9 +21,26 @@ pub async fn health_check(env: &Env) -> Result<Response> {
         Err(_) => "unavailable",
     };
     status["cache"] = serde_json::json!({
+        "backend": CacheConfig::from_env(env).backend.as_str(),
         "kv": kv_status
     });
 
+    // Remaining rate limit budget for the configured token
+    status["rate_limit"] = match WorkerRateLimiter::from_env(env) {
+        Some(limiter) => {
+            let config = limiter.config();
+            serde_json::json!({
+                "enabled": true,
+                "backend": limiter.backend(),
+                "mode": config.mode.as_str(),
+                "capacity": config.capacity,
+                "refill_per_hour": config.refill_per_hour,
+                "remaining": limiter.remaining(&WorkerRateLimiter::key_for(env)).await.ok(),
+            })
+        }
+        None => serde_json::json!({ "enabled": false }),
+    };
+
     // Check if API token is configured (without exposing it)
     let has_token = env.secret("COURTLISTENER_API_TOKEN").is_ok();
     status["auth"] = serde_json::json!({
@@ -1,11 +1,13 @@
 //! Route handlers for the CourtListener Worker
 
+pub mod admin;
 pub mod api;
 pub mod docs;
 pub mod health;
 pub mod proxy;
 pub mod webhooks;
 
+pub use admin::*;
 pub use api::*;
 pub use docs::*;
 pub use health::*;
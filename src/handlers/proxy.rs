@@ -1,8 +1,12 @@
 //! Generic proxy handler for CourtListener API endpoints
 
-use crate::api::ApiClient;
+use crate::api::rate_limit::acquire_for_env;
+use crate::api::{ApiClient, HttpMethod, RetryPolicy};
 use crate::config::get_api_base_url;
-use crate::utils::{json_response, sanitize_error};
+use crate::errors::CourtListenerError;
+use crate::utils::{
+    error_response, fetched_json_result, json_result, log_attempts, upstream_error, with_attempts,
+};
 use worker::*;
 
 /// Generic proxy endpoint - forwards requests to any CourtListener API endpoint
@@ -11,7 +15,12 @@ use worker::*;
 /// Security: Validates that the path is a valid CourtListener API endpoint
 /// Supports GET, POST, PUT, PATCH, DELETE methods
 /// Handles both /api/proxy/*path and direct endpoints like /api/docket-alerts
-pub async fn proxy_api_request(req: &Request, env: &Env, body: Option<&str>) -> Result<Response> {
+pub async fn proxy_api_request(
+    req: &Request,
+    env: &Env,
+    ctx: &Context,
+    body: Option<&str>,
+) -> Result<Response> {
     let url = req.url()?;
     let path = url.path();
     let method = req.method();
@@ -71,103 +80,102 @@ pub async fn proxy_api_request(req: &Request, env: &Env, body: Option<&str>) ->
     // Handle different HTTP methods
     match method {
         Method::Get => {
-            let data: serde_json::Value = ApiClient::fetch_json(env, &full_endpoint, req).await?;
-            json_response(&data)
+            let data =
+                ApiClient::fetch_with_context::<serde_json::Value>(env, ctx, &full_endpoint, req)
+                    .await;
+            fetched_json_result(req, data)
         }
-        Method::Post | Method::Put | Method::Patch => {
-            // Body is passed as parameter (read in router handler)
-            let body_str = body.unwrap_or("");
-
-            // Create request with body
-            use worker::wasm_bindgen::JsValue;
-            use worker::RequestInit;
-
-            let headers = worker::Headers::new();
-            headers.set("Accept", "application/json")?;
-            headers.set("Content-Type", "application/json")?;
-            headers.set(
-                "User-Agent",
-                &format!("courtlistener-worker/{}", env!("CARGO_PKG_VERSION")),
-            )?;
-
-            // Add API token if available
-            if let Ok(token) = env.secret("COURTLISTENER_API_TOKEN") {
-                headers.set("Authorization", &format!("Token {}", token))?;
-            }
+        Method::Post | Method::Put | Method::Patch | Method::Delete => {
+            // Body is passed as parameter (read in router handler); DELETE sends none
+            let body = body.filter(|b| !b.is_empty() && method != Method::Delete);
+
+            // Only PUT and DELETE are idempotent, so POST/PATCH get a single attempt
+            // Each attempt takes a rate limit token
+            let retried = RetryPolicy::from_env(env)
+                .run(
+                    http_method(&method),
+                    || async {
+                        acquire_for_env(env).await?;
+                        send_upstream(env, method.clone(), &api_url, body).await
+                    },
+                    Delay::from,
+                )
+                .await;
+            let attempts = retried.attempts;
+
+            let response = match retried.result {
+                Ok(mut resp) if (200..300).contains(&resp.status_code()) => {
+                    log_attempts(req, &full_endpoint, attempts, true);
+                    // DELETE (and some writes) may return an empty body
+                    let text = resp.text().await.unwrap_or_default();
+                    if text.is_empty() {
+                        Response::ok("")
+                    } else {
+                        json_result(parse_json(&text))
+                    }
+                }
+                Ok(mut resp) => {
+                    log_attempts(req, &full_endpoint, attempts, false);
+                    error_response(&upstream_error(&mut resp).await)
+                }
+                Err(e) => {
+                    log_attempts(req, &full_endpoint, attempts, false);
+                    error_response(&e)
+                }
+            }?;
+            with_attempts(response, attempts)
+        }
+        _ => Response::error("Method not supported", 405),
+    }
+}
 
-            let init = RequestInit {
-                method: method.clone(),
-                headers,
-                body: if body_str.is_empty() {
-                    None
-                } else {
-                    Some(JsValue::from_str(body_str))
-                },
-                ..Default::default()
-            };
-
-            let api_req = Request::new_with_init(&api_url, &init)?;
-            let mut resp = Fetch::Request(api_req).send().await?;
-
-            let status = resp.status_code();
-            let text = resp.text().await?;
-
-            if !(200..300).contains(&status) {
-                return Err(worker::Error::RustError(format!(
-                    "API returned {}: {}",
-                    status,
-                    sanitize_error(&text)
-                )));
-            }
+/// Send a write request to the upstream API
+async fn send_upstream(
+    env: &Env,
+    method: Method,
+    api_url: &str,
+    body: Option<&str>,
+) -> crate::errors::Result<Response> {
+    use worker::wasm_bindgen::JsValue;
+
+    let headers = worker::Headers::new();
+    headers.set("Accept", "application/json")?;
+    if body.is_some() {
+        headers.set("Content-Type", "application/json")?;
+    }
+    headers.set(
+        "User-Agent",
+        &format!("courtlistener-worker/{}", env!("CARGO_PKG_VERSION")),
+    )?;
+
+    // Add API token if available
+    if let Ok(token) = env.secret("COURTLISTENER_API_TOKEN") {
+        headers.set("Authorization", &format!("Token {}", token))?;
+    }
 
-            let data: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
-                worker::Error::RustError(format!("Failed to parse response: {}", e))
-            })?;
-            json_response(&data)
-        }
-        Method::Delete => {
-            let headers = worker::Headers::new();
-            headers.set("Accept", "application/json")?;
-            headers.set(
-                "User-Agent",
-                &format!("courtlistener-worker/{}", env!("CARGO_PKG_VERSION")),
-            )?;
-
-            if let Ok(token) = env.secret("COURTLISTENER_API_TOKEN") {
-                headers.set("Authorization", &format!("Token {}", token))?;
-            }
+    let init = RequestInit {
+        method,
+        headers,
+        body: body.map(JsValue::from_str),
+        ..Default::default()
+    };
 
-            let init = RequestInit {
-                method: Method::Delete,
-                headers,
-                body: None,
-                ..Default::default()
-            };
-
-            let api_req = Request::new_with_init(&api_url, &init)?;
-            let mut resp = Fetch::Request(api_req).send().await?;
-
-            let status = resp.status_code();
-            let text = resp.text().await.unwrap_or_default();
-
-            if !(200..300).contains(&status) {
-                return Err(worker::Error::RustError(format!(
-                    "API returned {}: {}",
-                    status,
-                    sanitize_error(&text)
-                )));
-            }
+    let api_req = Request::new_with_init(api_url, &init)?;
+    Ok(Fetch::Request(api_req).send().await?)
+}
 
-            // DELETE may return empty body or JSON
-            if text.is_empty() {
-                Response::ok("")
-            } else {
-                let data: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
-                    worker::Error::RustError(format!("Failed to parse response: {}", e))
-                })?;
-                json_response(&data)
-            }
-        }
-        _ => Response::error("Method not supported", 405),
+/// Transport method for retry decisions
+fn http_method(method: &Method) -> HttpMethod {
+    match method {
+        Method::Put => HttpMethod::Put,
+        Method::Patch => HttpMethod::Patch,
+        Method::Delete => HttpMethod::Delete,
+        _ => HttpMethod::Post,
     }
 }
+
+/// Parse an upstream JSON body
+fn parse_json(text: &str) -> crate::errors::Result<serde_json::Value> {
+    serde_json::from_str(text)
+        .map_err(|e| CourtListenerError::Json(format!("Failed to parse response: {}", e)))
+}
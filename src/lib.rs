@@ -6,6 +6,7 @@
 //! This library provides:
 //! - Complete type definitions for all CourtListener API resources
 //! - High-level API client for Cloudflare Workers
+//! - Transport-agnostic client for native services (`native` feature)
 //! - Validation support using the `validator` crate
 //! - Configuration and utilities
 //!
@@ -53,6 +54,30 @@
 //! }
 //! ```
 //!
+//! ## Native Usage
+//!
+//! Outside of Cloudflare Workers, enable the `native` feature and use
+//! `CourtListenerClient`:
+//!
+//! ```ignore
+//! use courtlistener_worker::{CourtListenerClient, CourtsResponse};
+//!
+//! // Reads COURTLISTENER_API_TOKEN and COURTLISTENER_API_BASE_URL from the environment
+//! let client = CourtListenerClient::native();
+//! let courts: CourtsResponse = client.fetch_json("/courts/", None).await?;
+//!
+//! // Or use the typed resource methods
+//! let scotus = client.courts().get("scotus").await?;
+//! let dockets = client.dockets().list_with(&[("court", "scotus")]).await?;
+//!
+//! // Follow `next` links automatically
+//! use futures_util::StreamExt;
+//! let mut opinions = client.opinions().stream(PaginationOptions::new().max_items(500));
+//! while let Some(opinion) = opinions.next().await {
+//!     let opinion = opinion?;
+//! }
+//! ```
+//!
 //! ## Validation
 //!
 //! For types that implement `validator::Validate`, use `fetch_json_validated`:
@@ -92,16 +117,20 @@
 // Core library modules (public API - always available)
 pub mod config;
 pub mod errors;
+pub mod query;
 pub mod types;
 
-// Worker-dependent modules (optional, requires worker feature)
-#[cfg(feature = "worker")]
+// API clients (transport-agnostic client always available, ApiClient requires worker)
 pub mod api;
 
+// Cache backends (KV and Cache API backends require worker)
+pub mod cache;
+
+// Internal modules (not part of public API)
+mod time;
+
 // Internal modules (not part of public API, require worker)
 #[cfg(feature = "worker")]
-mod cache;
-#[cfg(feature = "worker")]
 mod handlers;
 #[cfg(feature = "worker")]
 mod utils;
@@ -117,7 +146,8 @@ pub use types::*;
 // Re-export config constants for convenience
 pub use config::{get_api_base_url, API_BASE_URL, API_VERSION, API_VERSION_PATH};
 
-// Re-export high-level client interfaces (only when worker feature is enabled)
+// Re-export high-level client interfaces
+pub use api::{CourtListenerClient, HttpTransport, PaginationOptions};
 #[cfg(feature = "worker")]
 pub use api::ApiClient;
 
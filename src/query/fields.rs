@@ -0,0 +1,296 @@
+//! Filterable fields for each CourtListener resource
+//!
+//! Related fields nest the related resource's field enum, e.g.
+//! `DocketField::Court(CourtField::Jurisdiction)` is `court__jurisdiction`.
+
+use crate::query::filter::{Filter, FilterField};
+
+/// Join a relation name with a related field path
+fn related(name: &str, field: &impl FilterField) -> String {
+    format!("{}__{}", name, field.path())
+}
+
+/// Court fields (`/courts/`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CourtField {
+    Id,
+    DateModified,
+    InUse,
+    HasOpinionScraper,
+    HasOralArgumentScraper,
+    Position,
+    CitationString,
+    ShortName,
+    FullName,
+    Url,
+    StartDate,
+    EndDate,
+    Jurisdiction,
+}
+
+impl FilterField for CourtField {
+    fn path(&self) -> String {
+        match self {
+            Self::Id => "id",
+            Self::DateModified => "date_modified",
+            Self::InUse => "in_use",
+            Self::HasOpinionScraper => "has_opinion_scraper",
+            Self::HasOralArgumentScraper => "has_oral_argument_scraper",
+            Self::Position => "position",
+            Self::CitationString => "citation_string",
+            Self::ShortName => "short_name",
+            Self::FullName => "full_name",
+            Self::Url => "url",
+            Self::StartDate => "start_date",
+            Self::EndDate => "end_date",
+            Self::Jurisdiction => "jurisdiction",
+        }
+        .to_string()
+    }
+}
+
+/// Docket fields (`/dockets/`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocketField {
+    Id,
+    Court(CourtField),
+    AssignedTo(PersonField),
+    ReferredTo(PersonField),
+    DateCreated,
+    DateModified,
+    DateFiled,
+    DateTerminated,
+    DateLastFiling,
+    DateArgued,
+    DateBlocked,
+    Blocked,
+    Source,
+    CaseName,
+    CaseNameShort,
+    CaseNameFull,
+    DocketNumber,
+    DocketNumberCore,
+    PacerCaseId,
+    NatureOfSuit,
+    Cause,
+    JuryDemand,
+    JurisdictionType,
+}
+
+impl FilterField for DocketField {
+    fn path(&self) -> String {
+        match self {
+            Self::Court(f) => return related("court", f),
+            Self::AssignedTo(f) => return related("assigned_to", f),
+            Self::ReferredTo(f) => return related("referred_to", f),
+            Self::Id => "id",
+            Self::DateCreated => "date_created",
+            Self::DateModified => "date_modified",
+            Self::DateFiled => "date_filed",
+            Self::DateTerminated => "date_terminated",
+            Self::DateLastFiling => "date_last_filing",
+            Self::DateArgued => "date_argued",
+            Self::DateBlocked => "date_blocked",
+            Self::Blocked => "blocked",
+            Self::Source => "source",
+            Self::CaseName => "case_name",
+            Self::CaseNameShort => "case_name_short",
+            Self::CaseNameFull => "case_name_full",
+            Self::DocketNumber => "docket_number",
+            Self::DocketNumberCore => "docket_number_core",
+            Self::PacerCaseId => "pacer_case_id",
+            Self::NatureOfSuit => "nature_of_suit",
+            Self::Cause => "cause",
+            Self::JuryDemand => "jury_demand",
+            Self::JurisdictionType => "jurisdiction_type",
+        }
+        .to_string()
+    }
+}
+
+/// Opinion cluster fields (`/clusters/`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClusterField {
+    Id,
+    Docket(DocketField),
+    Panel(PersonField),
+    DateCreated,
+    DateModified,
+    DateFiled,
+    DateBlocked,
+    Blocked,
+    CaseName,
+    CaseNameShort,
+    CaseNameFull,
+    ScdbId,
+    Source,
+    CitationCount,
+    PrecedentialStatus,
+}
+
+impl FilterField for ClusterField {
+    fn path(&self) -> String {
+        match self {
+            Self::Docket(f) => return related("docket", f),
+            Self::Panel(f) => return related("panel", f),
+            Self::Id => "id",
+            Self::DateCreated => "date_created",
+            Self::DateModified => "date_modified",
+            Self::DateFiled => "date_filed",
+            Self::DateBlocked => "date_blocked",
+            Self::Blocked => "blocked",
+            Self::CaseName => "case_name",
+            Self::CaseNameShort => "case_name_short",
+            Self::CaseNameFull => "case_name_full",
+            Self::ScdbId => "scdb_id",
+            Self::Source => "source",
+            Self::CitationCount => "citation_count",
+            Self::PrecedentialStatus => "precedential_status",
+        }
+        .to_string()
+    }
+}
+
+/// Opinion fields (`/opinions/`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpinionField {
+    Id,
+    Cluster(ClusterField),
+    Author(PersonField),
+    JoinedBy(PersonField),
+    DateCreated,
+    DateModified,
+    Type,
+    PerCuriam,
+    Sha1,
+    PageCount,
+    ExtractedByOcr,
+}
+
+impl FilterField for OpinionField {
+    fn path(&self) -> String {
+        match self {
+            Self::Cluster(f) => return related("cluster", f),
+            Self::Author(f) => return related("author", f),
+            Self::JoinedBy(f) => return related("joined_by", f),
+            Self::Id => "id",
+            Self::DateCreated => "date_created",
+            Self::DateModified => "date_modified",
+            Self::Type => "type",
+            Self::PerCuriam => "per_curiam",
+            Self::Sha1 => "sha1",
+            Self::PageCount => "page_count",
+            Self::ExtractedByOcr => "extracted_by_ocr",
+        }
+        .to_string()
+    }
+}
+
+/// Person fields (`/people/`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PersonField {
+    Id,
+    DateCreated,
+    DateModified,
+    DateDob,
+    DateDod,
+    NameFirst,
+    NameMiddle,
+    NameLast,
+    NameSuffix,
+    Slug,
+    Gender,
+    Race,
+    DobCity,
+    DobState,
+    DodCity,
+    DodState,
+    FjcId,
+}
+
+impl FilterField for PersonField {
+    fn path(&self) -> String {
+        match self {
+            Self::Id => "id",
+            Self::DateCreated => "date_created",
+            Self::DateModified => "date_modified",
+            Self::DateDob => "date_dob",
+            Self::DateDod => "date_dod",
+            Self::NameFirst => "name_first",
+            Self::NameMiddle => "name_middle",
+            Self::NameLast => "name_last",
+            Self::NameSuffix => "name_suffix",
+            Self::Slug => "slug",
+            Self::Gender => "gender",
+            Self::Race => "race",
+            Self::DobCity => "dob_city",
+            Self::DobState => "dob_state",
+            Self::DodCity => "dod_city",
+            Self::DodState => "dod_state",
+            Self::FjcId => "fjc_id",
+        }
+        .to_string()
+    }
+}
+
+/// Oral argument audio fields (`/audio/`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioField {
+    Id,
+    Docket(DocketField),
+    Judges(PersonField),
+    DateCreated,
+    DateModified,
+    DateBlocked,
+    Blocked,
+    Source,
+    Sha1,
+    Duration,
+}
+
+impl FilterField for AudioField {
+    fn path(&self) -> String {
+        match self {
+            Self::Docket(f) => return related("docket", f),
+            Self::Judges(f) => return related("judges", f),
+            Self::Id => "id",
+            Self::DateCreated => "date_created",
+            Self::DateModified => "date_modified",
+            Self::DateBlocked => "date_blocked",
+            Self::Blocked => "blocked",
+            Self::Source => "source",
+            Self::Sha1 => "sha1",
+            Self::Duration => "duration",
+        }
+        .to_string()
+    }
+}
+
+/// Citation graph fields (`/opinions-cited/`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CitationField {
+    Id,
+    CitingOpinion(OpinionField),
+    CitedOpinion(OpinionField),
+    Depth,
+}
+
+impl FilterField for CitationField {
+    fn path(&self) -> String {
+        match self {
+            Self::CitingOpinion(f) => related("citing_opinion", f),
+            Self::CitedOpinion(f) => related("cited_opinion", f),
+            Self::Id => "id".to_string(),
+            Self::Depth => "depth".to_string(),
+        }
+    }
+}
+
+/// Filter builders for each resource
+pub type CourtFilter = Filter<CourtField>;
+pub type DocketFilter = Filter<DocketField>;
+pub type ClusterFilter = Filter<ClusterField>;
+pub type OpinionFilter = Filter<OpinionField>;
+pub type PersonFilter = Filter<PersonField>;
+pub type AudioFilter = Filter<AudioField>;
+pub type CitationFilter = Filter<CitationField>;
@@ -0,0 +1,233 @@
+//! Django REST Framework style filter and ordering builder
+//!
+//! Builds query strings such as
+//! `court__jurisdiction=F&id__range=500,1000&order_by=-date_modified&fields=id,case_name`
+//! from typed fields instead of hand-written strings:
+//!
+//! ```
+//! use courtlistener_worker::query::{CourtField, DocketField, DocketFilter, Order, ToQuery};
+//!
+//! let filter = DocketFilter::new()
+//!     .exact(DocketField::Court(CourtField::Jurisdiction), "F")
+//!     .range(DocketField::Id, 500, 1000)
+//!     .order_by(DocketField::DateModified, Order::Desc)
+//!     .fields([DocketField::Id, DocketField::CaseName]);
+//!
+//! assert_eq!(
+//!     filter.to_query(),
+//!     "court__jurisdiction=F&id__range=500,1000&order_by=-date_modified&fields=id,case_name"
+//! );
+//! ```
+
+use crate::query::{encode, ToQuery};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// A filterable field of a resource
+/// `path()` returns the Django field path, with related fields joined by "__"
+pub trait FilterField {
+    fn path(&self) -> String;
+}
+
+/// Django field lookups supported by CourtListener filters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lookup {
+    Exact,
+    IExact,
+    In,
+    Range,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    IsNull,
+    StartsWith,
+    IStartsWith,
+    EndsWith,
+    IEndsWith,
+    Contains,
+    IContains,
+}
+
+impl Lookup {
+    /// Suffix appended to the field path ("" for exact matches)
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Self::Exact => "",
+            Self::IExact => "__iexact",
+            Self::In => "__in",
+            Self::Range => "__range",
+            Self::Gt => "__gt",
+            Self::Gte => "__gte",
+            Self::Lt => "__lt",
+            Self::Lte => "__lte",
+            Self::IsNull => "__isnull",
+            Self::StartsWith => "__startswith",
+            Self::IStartsWith => "__istartswith",
+            Self::EndsWith => "__endswith",
+            Self::IEndsWith => "__iendswith",
+            Self::Contains => "__contains",
+            Self::IContains => "__icontains",
+        }
+    }
+}
+
+/// Sort direction for `order_by`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// Typed filter/ordering builder for a resource with fields `F`
+#[derive(Debug, Clone)]
+pub struct Filter<F> {
+    params: Vec<(String, String)>,
+    order_by: Vec<String>,
+    fields: Vec<String>,
+    omit: Vec<String>,
+    _field: PhantomData<fn() -> F>,
+}
+
+impl<F> Default for Filter<F> {
+    fn default() -> Self {
+        Self {
+            params: Vec::new(),
+            order_by: Vec::new(),
+            fields: Vec::new(),
+            omit: Vec::new(),
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: FilterField> Filter<F> {
+    /// Empty filter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a `field__lookup=value` condition
+    pub fn lookup(mut self, field: F, lookup: Lookup, value: impl Display) -> Self {
+        self.params.push((
+            format!("{}{}", field.path(), lookup.suffix()),
+            value.to_string(),
+        ));
+        self
+    }
+
+    /// `field=value`
+    pub fn exact(self, field: F, value: impl Display) -> Self {
+        self.lookup(field, Lookup::Exact, value)
+    }
+
+    /// `field__iexact=value`
+    pub fn iexact(self, field: F, value: impl Display) -> Self {
+        self.lookup(field, Lookup::IExact, value)
+    }
+
+    /// `field__in=a,b,c`
+    pub fn is_in<V: Display>(self, field: F, values: impl IntoIterator<Item = V>) -> Self {
+        self.lookup(field, Lookup::In, join(values))
+    }
+
+    /// `field__range=low,high` (inclusive)
+    pub fn range(self, field: F, low: impl Display, high: impl Display) -> Self {
+        self.lookup(field, Lookup::Range, format!("{},{}", low, high))
+    }
+
+    /// `field__gt=value`
+    pub fn gt(self, field: F, value: impl Display) -> Self {
+        self.lookup(field, Lookup::Gt, value)
+    }
+
+    /// `field__gte=value`
+    pub fn gte(self, field: F, value: impl Display) -> Self {
+        self.lookup(field, Lookup::Gte, value)
+    }
+
+    /// `field__lt=value`
+    pub fn lt(self, field: F, value: impl Display) -> Self {
+        self.lookup(field, Lookup::Lt, value)
+    }
+
+    /// `field__lte=value`
+    pub fn lte(self, field: F, value: impl Display) -> Self {
+        self.lookup(field, Lookup::Lte, value)
+    }
+
+    /// `field__isnull=true|false`
+    pub fn is_null(self, field: F, is_null: bool) -> Self {
+        self.lookup(field, Lookup::IsNull, is_null)
+    }
+
+    /// `field__startswith=value`
+    pub fn starts_with(self, field: F, value: impl Display) -> Self {
+        self.lookup(field, Lookup::StartsWith, value)
+    }
+
+    /// `field__icontains=value`
+    pub fn icontains(self, field: F, value: impl Display) -> Self {
+        self.lookup(field, Lookup::IContains, value)
+    }
+
+    /// Add a sort key; call repeatedly for secondary keys
+    pub fn order_by(mut self, field: F, order: Order) -> Self {
+        let path = field.path();
+        self.order_by.push(match order {
+            Order::Asc => path,
+            Order::Desc => format!("-{}", path),
+        });
+        self
+    }
+
+    /// Only return these fields (`fields=`)
+    pub fn fields(mut self, fields: impl IntoIterator<Item = F>) -> Self {
+        self.fields.extend(fields.into_iter().map(|f| f.path()));
+        self
+    }
+
+    /// Leave these fields out of the response (`omit=`)
+    pub fn omit(mut self, fields: impl IntoIterator<Item = F>) -> Self {
+        self.omit.extend(fields.into_iter().map(|f| f.path()));
+        self
+    }
+
+    /// Add an untyped parameter for filters not modelled by `F`
+    pub fn param(mut self, key: impl Into<String>, value: impl Display) -> Self {
+        self.params.push((key.into(), value.to_string()));
+        self
+    }
+}
+
+impl<F> ToQuery for Filter<F> {
+    fn to_query(&self) -> String {
+        let mut pairs: Vec<(&str, String)> = self
+            .params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.clone()))
+            .collect();
+        if !self.order_by.is_empty() {
+            pairs.push(("order_by", self.order_by.join(",")));
+        }
+        if !self.fields.is_empty() {
+            pairs.push(("fields", self.fields.join(",")));
+        }
+        if !self.omit.is_empty() {
+            pairs.push(("omit", self.omit.join(",")));
+        }
+        pairs
+            .iter()
+            .map(|(k, v)| format!("{}={}", encode(k), encode(v)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+fn join<V: Display>(values: impl IntoIterator<Item = V>) -> String {
+    values
+        .into_iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,81 @@
+//! Query string building for CourtListener API requests
+
+pub mod fields;
+pub mod filter;
+pub mod search;
+
+pub use fields::*;
+pub use filter::{Filter, FilterField, Lookup, Order};
+pub use search::{search_type_of, SearchField, SearchOrder, SearchQuery};
+
+use crate::errors::Result;
+
+/// Types that can be serialized into a URL query string
+/// The returned string is already percent-encoded and has no leading '?'
+pub trait ToQuery {
+    fn to_query(&self) -> String;
+
+    /// Reject invalid parameter combinations before a request is sent
+    fn validate_query(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ToQuery for str {
+    fn to_query(&self) -> String {
+        self.trim_start_matches('?').to_string()
+    }
+}
+
+impl ToQuery for String {
+    fn to_query(&self) -> String {
+        self.as_str().to_query()
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> ToQuery for [(K, V)] {
+    fn to_query(&self) -> String {
+        self.iter()
+            .map(|(k, v)| format!("{}={}", encode(k.as_ref()), encode(v.as_ref())))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>, const N: usize> ToQuery for [(K, V); N] {
+    fn to_query(&self) -> String {
+        self.as_slice().to_query()
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> ToQuery for Vec<(K, V)> {
+    fn to_query(&self) -> String {
+        self.as_slice().to_query()
+    }
+}
+
+impl<Q: ToQuery + ?Sized> ToQuery for &Q {
+    fn to_query(&self) -> String {
+        (**self).to_query()
+    }
+
+    fn validate_query(&self) -> Result<()> {
+        (**self).validate_query()
+    }
+}
+
+/// Percent-encode a query component
+/// RFC 3986 unreserved characters pass through, as do commas, which separate
+/// list values in filters like `id__in=1,2,3` and `fields=id,name`
+pub fn encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b',' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
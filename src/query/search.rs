@@ -0,0 +1,343 @@
+//! Search API query builder
+//!
+//! ```
+//! use courtlistener_worker::query::{SearchField, SearchOrder, SearchQuery, ToQuery};
+//!
+//! let query = SearchQuery::new()
+//!     .q("first amendment")
+//!     .field(SearchField::CaseName, "Tinker")
+//!     .courts(["scotus", "ca9"])
+//!     .filed_after("1960-01-01")
+//!     .order_by(SearchOrder::DateFiledDesc)
+//!     .highlight(true);
+//!
+//! assert!(query.validate_query().is_ok());
+//! assert_eq!(
+//!     query.to_query(),
+//!     "type=o&q=%28first%20amendment%29%20AND%20caseName%3A%28Tinker%29&court=scotus%20ca9\
+//!      &filed_after=1960-01-01&order_by=dateFiled%20desc&highlight=on"
+//! );
+//! ```
+
+use crate::errors::{CourtListenerError, Result};
+use crate::query::{encode, ToQuery};
+use crate::types::search::SearchType;
+
+/// Fields that can be targeted inside the `q` parameter (`field:(term)`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchField {
+    CaseName,
+    Judge,
+    Author,
+    Court,
+    Citation,
+    NeutralCite,
+    DocketNumber,
+    NatureOfSuit,
+    Cause,
+    Status,
+    Description,
+    PartyName,
+    AttorneyName,
+    Text,
+}
+
+impl SearchField {
+    /// Search engine field name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CaseName => "caseName",
+            Self::Judge => "judge",
+            Self::Author => "author_id",
+            Self::Court => "court_id",
+            Self::Citation => "citation",
+            Self::NeutralCite => "neutralCite",
+            Self::DocketNumber => "docketNumber",
+            Self::NatureOfSuit => "suitNature",
+            Self::Cause => "cause",
+            Self::Status => "status",
+            Self::Description => "description",
+            Self::PartyName => "party",
+            Self::AttorneyName => "attorney",
+            Self::Text => "text",
+        }
+    }
+}
+
+/// Result ordering (`order_by=`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchOrder {
+    /// Relevance (all types)
+    Relevance,
+    DateFiledDesc,
+    DateFiledAsc,
+    /// Case law only
+    CiteCountDesc,
+    /// Case law only
+    CiteCountAsc,
+    /// RECAP documents only
+    EntryDateFiledDesc,
+    /// RECAP documents only
+    EntryDateFiledAsc,
+    /// Oral arguments only
+    DateArguedDesc,
+    /// Oral arguments only
+    DateArguedAsc,
+    /// Judges only
+    NameAsc,
+    /// Judges only
+    DateOfBirthDesc,
+    /// Judges only
+    DateOfBirthAsc,
+}
+
+impl SearchOrder {
+    /// Value of the `order_by=` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Relevance => "score desc",
+            Self::DateFiledDesc => "dateFiled desc",
+            Self::DateFiledAsc => "dateFiled asc",
+            Self::CiteCountDesc => "citeCount desc",
+            Self::CiteCountAsc => "citeCount asc",
+            Self::EntryDateFiledDesc => "entry_date_filed desc",
+            Self::EntryDateFiledAsc => "entry_date_filed asc",
+            Self::DateArguedDesc => "dateArgued desc",
+            Self::DateArguedAsc => "dateArgued asc",
+            Self::NameAsc => "name_reverse asc",
+            Self::DateOfBirthDesc => "dob desc",
+            Self::DateOfBirthAsc => "dob asc",
+        }
+    }
+
+    /// Whether this ordering is accepted for a search type
+    pub fn is_valid_for(&self, search_type: SearchType) -> bool {
+        use SearchType::*;
+        match self {
+            Self::Relevance => true,
+            Self::DateFiledDesc | Self::DateFiledAsc => {
+                matches!(search_type, Opinions | Recap | RecapDocuments | Dockets)
+            }
+            Self::CiteCountDesc | Self::CiteCountAsc => search_type == Opinions,
+            Self::EntryDateFiledDesc | Self::EntryDateFiledAsc => {
+                matches!(search_type, Recap | RecapDocuments)
+            }
+            Self::DateArguedDesc | Self::DateArguedAsc => search_type == OralArguments,
+            Self::NameAsc | Self::DateOfBirthDesc | Self::DateOfBirthAsc => search_type == People,
+        }
+    }
+}
+
+/// Builder for search API query parameters
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    search_type: SearchType,
+    q: Option<String>,
+    terms: Vec<(SearchField, String)>,
+    courts: Vec<String>,
+    filed_after: Option<String>,
+    filed_before: Option<String>,
+    order_by: Option<SearchOrder>,
+    highlight: bool,
+    semantic: bool,
+    page_size: Option<u32>,
+}
+
+impl SearchQuery {
+    /// Case law search with no query
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the result type
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.search_type = search_type;
+        self
+    }
+
+    /// Set the free-text query
+    pub fn q(mut self, q: impl Into<String>) -> Self {
+        self.q = Some(q.into());
+        self
+    }
+
+    /// Add a field-scoped term, ANDed with the rest of the query
+    pub fn field(mut self, field: SearchField, term: impl Into<String>) -> Self {
+        self.terms.push((field, term.into()));
+        self
+    }
+
+    /// Restrict results to these court ids
+    pub fn courts<S: Into<String>>(mut self, courts: impl IntoIterator<Item = S>) -> Self {
+        self.courts.extend(courts.into_iter().map(Into::into));
+        self
+    }
+
+    /// Only results filed on or after this date (YYYY-MM-DD)
+    pub fn filed_after(mut self, date: impl Into<String>) -> Self {
+        self.filed_after = Some(date.into());
+        self
+    }
+
+    /// Only results filed on or before this date (YYYY-MM-DD)
+    pub fn filed_before(mut self, date: impl Into<String>) -> Self {
+        self.filed_before = Some(date.into());
+        self
+    }
+
+    /// Set the result ordering
+    pub fn order_by(mut self, order: SearchOrder) -> Self {
+        self.order_by = Some(order);
+        self
+    }
+
+    /// Enable `<mark>` highlighting in snippet fields
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Use semantic instead of keyword search (case law only)
+    pub fn semantic(mut self, semantic: bool) -> Self {
+        self.semantic = semantic;
+        self
+    }
+
+    /// Number of results per page
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Selected result type
+    pub fn selected_type(&self) -> SearchType {
+        self.search_type
+    }
+
+    /// Combined `q` parameter including field-scoped terms
+    /// The raw query is grouped so its own operators (`roe OR wade`) do not
+    /// bind to the field terms
+    fn full_q(&self) -> Option<String> {
+        let mut parts: Vec<String> = match &self.q {
+            Some(q) if !self.terms.is_empty() => vec![format!("({})", q)],
+            q => q.iter().cloned().collect(),
+        };
+        parts.extend(
+            self.terms
+                .iter()
+                .map(|(field, term)| format!("{}:({})", field.as_str(), term)),
+        );
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(" AND "))
+        }
+    }
+}
+
+impl ToQuery for SearchQuery {
+    fn to_query(&self) -> String {
+        let mut pairs: Vec<(&str, String)> = vec![("type", self.search_type.as_str().to_string())];
+        if let Some(q) = self.full_q() {
+            pairs.push(("q", q));
+        }
+        if !self.courts.is_empty() {
+            pairs.push(("court", self.courts.join(" ")));
+        }
+        if let Some(date) = &self.filed_after {
+            pairs.push(("filed_after", date.clone()));
+        }
+        if let Some(date) = &self.filed_before {
+            pairs.push(("filed_before", date.clone()));
+        }
+        if let Some(order) = self.order_by {
+            pairs.push(("order_by", order.as_str().to_string()));
+        }
+        if self.highlight {
+            pairs.push(("highlight", "on".to_string()));
+        }
+        if self.semantic {
+            pairs.push(("semantic", "true".to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            pairs.push(("page_size", page_size.to_string()));
+        }
+        pairs
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, encode(v)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    fn validate_query(&self) -> Result<()> {
+        if self.semantic {
+            if self.search_type != SearchType::Opinions {
+                return Err(invalid(format!(
+                    "semantic search is only available for type=o, not type={}",
+                    self.search_type.as_str()
+                )));
+            }
+            if self.q.as_deref().is_none_or(|q| q.trim().is_empty()) {
+                return Err(invalid("semantic search requires a query".to_string()));
+            }
+            if !self.terms.is_empty() {
+                return Err(invalid(
+                    "semantic search does not support field-scoped terms".to_string(),
+                ));
+            }
+        }
+        if let Some(order) = self.order_by {
+            if !order.is_valid_for(self.search_type) {
+                return Err(invalid(format!(
+                    "order_by={} is not valid for type={}",
+                    order.as_str(),
+                    self.search_type.as_str()
+                )));
+            }
+        }
+        if let (Some(after), Some(before)) = (&self.filed_after, &self.filed_before) {
+            if is_iso_date(after) && is_iso_date(before) && after > before {
+                return Err(invalid(format!(
+                    "filed_after ({}) is later than filed_before ({})",
+                    after, before
+                )));
+            }
+        }
+        if self.courts.iter().any(|c| c.is_empty() || c.contains(' ')) {
+            return Err(invalid(
+                "court ids must be non-empty and contain no spaces".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Search type selected by the `type=` parameter of a query string
+/// Defaults to case law when the parameter is absent
+pub fn search_type_of(query: &str) -> Result<SearchType> {
+    let value = query
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("type="));
+    match value {
+        None | Some("") => Ok(SearchType::default()),
+        Some(value) => SearchType::from_param(value)
+            .ok_or_else(|| invalid(format!("unknown search type: type={}", value))),
+    }
+}
+
+fn invalid(message: String) -> CourtListenerError {
+    CourtListenerError::InvalidRequest(message)
+}
+
+/// Whether a string looks like a YYYY-MM-DD date (so string order is date order)
+fn is_iso_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes
+            .iter()
+            .enumerate()
+            .all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit())
+}
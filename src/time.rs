@@ -0,0 +1,16 @@
+//! Clock helpers shared by the rate limiter and cache
+
+/// Current time in milliseconds since the Unix epoch
+pub(crate) fn now_millis() -> u64 {
+    #[cfg(all(feature = "worker", target_arch = "wasm32"))]
+    {
+        worker::js_sys::Date::now() as u64
+    }
+    #[cfg(not(all(feature = "worker", target_arch = "wasm32")))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default()
+    }
+}
@@ -1,6 +1,10 @@
 //! Audio types
 
 use crate::types::common::PaginatedResponse;
+use crate::types::courts::ApiCourt;
+use crate::types::dockets::Docket;
+use crate::types::people::ApiPerson;
+use crate::types::reference::Ref;
 use serde::{Deserialize, Serialize};
 
 /// Audio recording (oral argument)
@@ -8,7 +12,7 @@ use serde::{Deserialize, Serialize};
 pub struct Audio {
     pub id: u32,
     pub resource_uri: Option<String>,
-    pub docket: Option<String>, // URL or docket_id
+    pub docket: Option<Ref<Docket>>,
     pub docket_id: Option<u32>,
     pub source: Option<String>,
     pub case_name: Option<String>,
@@ -17,7 +21,7 @@ pub struct Audio {
     pub date_created: Option<String>,
     pub date_modified: Option<String>,
     pub date_argued: Option<String>,
-    pub court: Option<String>, // URL or court_id
+    pub court: Option<Ref<ApiCourt>>,
     pub court_id: Option<String>,
     pub download_url: Option<String>,   // Original file from court
     pub local_path_mp3: Option<String>, // Enhanced MP3 file path
@@ -30,7 +34,7 @@ pub struct Audio {
     pub ia_date_first_change: Option<String>,
     pub date_blocked: Option<String>,
     pub blocked: Option<bool>,
-    pub judges: Option<Vec<String>>, // URLs or person_ids
+    pub judges: Option<Vec<Ref<ApiPerson>>>,
     pub absolute_url: Option<String>,
 }
 
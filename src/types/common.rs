@@ -1,16 +1,34 @@
 //! Core types and utilities for CourtListener API
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Paginated API response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedResponse<T> {
+    /// Total result count
+    /// Cursor-paginated endpoints may return a count URL instead; that is read as 0
+    #[serde(default, deserialize_with = "deserialize_count")]
     pub count: u32,
     pub next: Option<String>,
     pub previous: Option<String>,
     pub results: Vec<T>,
 }
 
+/// Accept a numeric count, a count URL (cursor pagination) or null
+fn deserialize_count<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Count {
+        Number(u32),
+        Url(serde::de::IgnoredAny),
+    }
+
+    Ok(match Option::<Count>::deserialize(deserializer)? {
+        Some(Count::Number(n)) => n,
+        Some(Count::Url(_)) | None => 0,
+    })
+}
+
 /// Type aliases for IDs
 pub type CourtId = String;
 pub type PersonId = u32;
@@ -18,6 +36,13 @@ pub type OpinionId = u32;
 pub type ClusterId = u32;
 pub type CitationId = u32;
 pub type DocketId = u32;
+pub type AudioId = u32;
+pub type DocketEntryId = u32;
+pub type RecapDocumentId = u32;
+pub type PartyId = u32;
+pub type AttorneyId = u32;
+pub type PositionId = u32;
+pub type SchoolId = u32;
 
 /// Jurisdiction types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
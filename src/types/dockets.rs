@@ -1,6 +1,10 @@
 //! Docket types
 
 use crate::types::common::PaginatedResponse;
+use crate::types::courts::ApiCourt;
+use crate::types::fjc::FjcIntegratedDatabase;
+use crate::types::people::ApiPerson;
+use crate::types::reference::Ref;
 use serde::{Deserialize, Serialize};
 
 /// Docket (case information)
@@ -8,15 +12,15 @@ use serde::{Deserialize, Serialize};
 pub struct Docket {
     pub id: u32,
     pub resource_uri: Option<String>,
-    pub court: Option<String>, // URL or court_id
+    pub court: Option<Ref<ApiCourt>>,
     pub court_id: Option<String>,
     pub original_court_info: Option<String>,
-    pub idb_data: Option<serde_json::Value>,
+    pub idb_data: Option<FjcIntegratedDatabase>,
     pub bankruptcy_information: Option<serde_json::Value>,
     pub clusters: Option<Vec<serde_json::Value>>,
     pub audio_files: Option<Vec<serde_json::Value>>,
-    pub assigned_to: Option<String>, // URL or person_id
-    pub referred_to: Option<String>,
+    pub assigned_to: Option<Ref<ApiPerson>>,
+    pub referred_to: Option<Ref<ApiPerson>>,
     pub absolute_url: Option<String>,
     pub date_created: Option<String>,
     pub date_modified: Option<String>,
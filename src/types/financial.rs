@@ -1,31 +1,304 @@
-//! Financial disclosure and FJC database types
+//! Financial disclosure types
 
+use crate::types::common::PaginatedResponse;
 use serde::{Deserialize, Serialize};
 
-/// Financial disclosure document
+/// Financial disclosure report filed by a judge, with its parts nested
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FinancialDisclosure {
     pub id: u32,
+    pub resource_uri: Option<String>,
+    pub person: Option<String>, // URL or person_id
     pub judge: Option<u32>,
+    pub investments: Option<Vec<Investment>>,
+    pub positions: Option<Vec<DisclosurePosition>>,
+    pub agreements: Option<Vec<Agreement>>,
+    pub non_investment_incomes: Option<Vec<NonInvestmentIncome>>,
+    pub spouse_incomes: Option<Vec<SpouseIncome>>,
+    pub reimbursements: Option<Vec<Reimbursement>>,
+    pub gifts: Option<Vec<Gift>>,
+    pub debts: Option<Vec<Debt>>,
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
     pub year: Option<u32>,
+    /// -1 = unknown, 0 = nomination, 1 = initial, 2 = annual, 3 = final
+    pub report_type: Option<i32>,
+    pub is_amended: Option<bool>,
+    pub download_filepath: Option<String>,
+    pub filepath: Option<String>,
+    pub sha1: Option<String>,
+    pub page_count: Option<u32>,
     pub page_number: Option<u32>,
     pub redacted: Option<bool>,
     pub download_url: Option<String>,
     pub thumbnail: Option<String>,
     pub thumbnail_size: Option<u32>,
+    pub thumbnail_status: Option<u32>,
+    pub addendum_content_raw: Option<String>,
+    pub addendum_redacted: Option<bool>,
+    pub has_been_extracted: Option<bool>,
 }
 
-/// Federal Judicial Center database entry
+/// Investment or trust asset (part VII of the report)
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FJCDatabase {
+pub struct Investment {
     pub id: u32,
-    pub judge_id: Option<u32>,
+    pub resource_uri: Option<String>,
+    pub financial_disclosure: Option<String>, // URL or financial_disclosure_id
     pub date_created: Option<String>,
     pub date_modified: Option<String>,
-    pub year: Option<u32>,
-    pub nid: Option<u32>,
-    pub name: Option<String>,
-    pub title: Option<String>,
-    pub court: Option<String>,
-    pub source_url: Option<String>,
+    pub page_number: Option<u32>,
+    pub description: Option<String>,
+    pub redacted: Option<bool>,
+    pub income_during_reporting_period_code: Option<IncomeCode>,
+    pub income_during_reporting_period_type: Option<String>,
+    pub gross_value_code: Option<GrossValueCode>,
+    pub gross_value_method: Option<ValueMethodCode>,
+    pub transaction_during_reporting_period: Option<String>,
+    pub transaction_date_raw: Option<String>,
+    pub transaction_date: Option<String>,
+    pub transaction_value_code: Option<GrossValueCode>,
+    pub transaction_gain_code: Option<IncomeCode>,
+    pub transaction_partner: Option<String>,
+    pub has_inferred_values: Option<bool>,
+}
+
+/// Position held outside the court (part I of the report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisclosurePosition {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub financial_disclosure: Option<String>, // URL or financial_disclosure_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub position: Option<String>,
+    pub organization_name: Option<String>,
+    pub redacted: Option<bool>,
+}
+
+/// Agreement or arrangement with an employer (part II of the report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Agreement {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub financial_disclosure: Option<String>, // URL or financial_disclosure_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub date_raw: Option<String>,
+    pub parties_and_terms: Option<String>,
+    pub redacted: Option<bool>,
+}
+
+/// Non-investment income of the filer (part III A of the report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NonInvestmentIncome {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub financial_disclosure: Option<String>, // URL or financial_disclosure_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub date_raw: Option<String>,
+    pub source_type: Option<String>,
+    pub income_amount: Option<String>,
+    pub redacted: Option<bool>,
+}
+
+/// Non-investment income of the filer's spouse (part III B of the report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpouseIncome {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub financial_disclosure: Option<String>, // URL or financial_disclosure_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub date_raw: Option<String>,
+    pub source_type: Option<String>,
+    pub redacted: Option<bool>,
+}
+
+/// Reimbursement of travel or other expenses (part IV of the report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reimbursement {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub financial_disclosure: Option<String>, // URL or financial_disclosure_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub source: Option<String>,
+    pub date_raw: Option<String>,
+    pub location: Option<String>,
+    pub purpose: Option<String>,
+    pub items_paid_or_provided: Option<String>,
+    pub redacted: Option<bool>,
+}
+
+/// Gift received by the filer (part V of the report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Gift {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub financial_disclosure: Option<String>, // URL or financial_disclosure_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub source: Option<String>,
+    pub description: Option<String>,
+    pub value: Option<String>,
+    pub redacted: Option<bool>,
+}
+
+/// Liability owed by the filer (part VI of the report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Debt {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub financial_disclosure: Option<String>, // URL or financial_disclosure_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub creditor_name: Option<String>,
+    pub description: Option<String>,
+    pub value_code: Option<GrossValueCode>,
+    pub redacted: Option<bool>,
+}
+
+/// Gross value codes (investments, transactions and debts)
+/// Codes that failed extraction or are unknown deserialize as `Unknown`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GrossValueCode {
+    /// $1 - $15,000
+    J,
+    /// $15,001 - $50,000
+    K,
+    /// $50,001 - $100,000
+    L,
+    /// $100,001 - $250,000
+    M,
+    /// $250,001 - $500,000
+    N,
+    /// $500,001 - $1,000,000
+    O,
+    /// $1,000,001 - $5,000,000
+    P1,
+    /// $5,000,001 - $25,000,000
+    P2,
+    /// $25,000,001 - $50,000,000
+    P3,
+    /// More than $50,000,000
+    P4,
+    #[serde(rename = "-1", other)]
+    Unknown,
 }
+
+impl GrossValueCode {
+    /// Dollar range as (min, max); max is None for the open-ended top code
+    pub fn range(&self) -> Option<(u64, Option<u64>)> {
+        Some(match self {
+            Self::J => (1, Some(15_000)),
+            Self::K => (15_001, Some(50_000)),
+            Self::L => (50_001, Some(100_000)),
+            Self::M => (100_001, Some(250_000)),
+            Self::N => (250_001, Some(500_000)),
+            Self::O => (500_001, Some(1_000_000)),
+            Self::P1 => (1_000_001, Some(5_000_000)),
+            Self::P2 => (5_000_001, Some(25_000_000)),
+            Self::P3 => (25_000_001, Some(50_000_000)),
+            Self::P4 => (50_000_001, None),
+            Self::Unknown => return None,
+        })
+    }
+}
+
+/// Income and gain codes (investment income and transaction gains)
+/// Codes that failed extraction or are unknown deserialize as `Unknown`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IncomeCode {
+    /// $1 - $1,000
+    A,
+    /// $1,001 - $2,500
+    B,
+    /// $2,501 - $5,000
+    C,
+    /// $5,001 - $15,000
+    D,
+    /// $15,001 - $50,000
+    E,
+    /// $50,001 - $100,000
+    F,
+    /// $100,001 - $1,000,000
+    G,
+    /// $1,000,001 - $5,000,000
+    H1,
+    /// More than $5,000,000
+    H2,
+    #[serde(rename = "-1", other)]
+    Unknown,
+}
+
+impl IncomeCode {
+    /// Dollar range as (min, max); max is None for the open-ended top code
+    pub fn range(&self) -> Option<(u64, Option<u64>)> {
+        Some(match self {
+            Self::A => (1, Some(1_000)),
+            Self::B => (1_001, Some(2_500)),
+            Self::C => (2_501, Some(5_000)),
+            Self::D => (5_001, Some(15_000)),
+            Self::E => (15_001, Some(50_000)),
+            Self::F => (50_001, Some(100_000)),
+            Self::G => (100_001, Some(1_000_000)),
+            Self::H1 => (1_000_001, Some(5_000_000)),
+            Self::H2 => (5_000_001, None),
+            Self::Unknown => return None,
+        })
+    }
+}
+
+/// How a gross value was determined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ValueMethodCode {
+    /// Appraisal
+    Q,
+    /// Cost (real estate only)
+    R,
+    /// Assessment
+    S,
+    /// Cash market
+    T,
+    /// Book value
+    U,
+    /// Other
+    V,
+    /// Estimated
+    W,
+    #[serde(rename = "-1", other)]
+    Unknown,
+}
+
+/// Former placeholder for IDB records
+#[deprecated(note = "use FjcIntegratedDatabase")]
+pub type FJCDatabase = crate::types::fjc::FjcIntegratedDatabase;
+
+/// Paginated financial disclosures response
+pub type FinancialDisclosuresResponse = PaginatedResponse<FinancialDisclosure>;
+
+/// Paginated investments response
+pub type InvestmentsResponse = PaginatedResponse<Investment>;
+
+/// Paginated disclosure positions response
+pub type DisclosurePositionsResponse = PaginatedResponse<DisclosurePosition>;
+
+/// Paginated agreements response
+pub type AgreementsResponse = PaginatedResponse<Agreement>;
+
+/// Paginated non-investment incomes response
+pub type NonInvestmentIncomesResponse = PaginatedResponse<NonInvestmentIncome>;
+
+/// Paginated spouse incomes response
+pub type SpouseIncomesResponse = PaginatedResponse<SpouseIncome>;
+
+/// Paginated reimbursements response
+pub type ReimbursementsResponse = PaginatedResponse<Reimbursement>;
+
+/// Paginated gifts response
+pub type GiftsResponse = PaginatedResponse<Gift>;
+
+/// Paginated debts response
+pub type DebtsResponse = PaginatedResponse<Debt>;
@@ -0,0 +1,492 @@
+//! Federal Judicial Center Integrated Database (IDB) types
+//!
+//! The IDB records case-level statistics for civil, criminal, appellate and
+//! bankruptcy cases. Numeric codes decode into enums; codes outside the FJC
+//! codebook keep their value in `Other`.
+
+use crate::types::common::PaginatedResponse;
+use serde::{Deserialize, Serialize};
+
+/// IDB record for a case
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FjcIntegratedDatabase {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub circuit: Option<String>,  // URL or court_id
+    pub district: Option<String>, // URL or court_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub dataset_source: Option<DatasetSource>,
+    pub office: Option<String>,
+    pub docket_number: Option<String>,
+    pub origin: Option<Origin>,
+    pub date_filed: Option<String>,
+    pub jurisdiction: Option<JurisdictionBasis>,
+    /// Nature of suit code, e.g. 440 (other civil rights)
+    pub nature_of_suit: Option<u32>,
+    /// Title of the U.S. Code under which the case was filed
+    pub title: Option<String>,
+    pub section: Option<String>,
+    pub subsection: Option<String>,
+    pub diversity_of_residence: Option<i32>,
+    pub class_action: Option<bool>,
+    /// Amount demanded, in thousands of dollars
+    pub monetary_demand: Option<i64>,
+    pub county_of_residence: Option<i32>,
+    pub arbitration_at_filing: Option<String>,
+    pub arbitration_at_termination: Option<String>,
+    pub multidistrict_litigation_docket_number: Option<String>,
+    pub plaintiff: Option<String>,
+    pub defendant: Option<String>,
+    pub date_transfer: Option<String>,
+    pub transfer_office: Option<String>,
+    pub transfer_docket_number: Option<String>,
+    pub transfer_origin: Option<String>,
+    pub date_terminated: Option<String>,
+    pub termination_class_action_status: Option<i32>,
+    pub procedural_progress: Option<ProceduralProgress>,
+    pub disposition: Option<Disposition>,
+    pub nature_of_judgement: Option<NatureOfJudgement>,
+    /// Amount received, in thousands of dollars
+    pub amount_received: Option<i64>,
+    pub judgment: Option<Judgment>,
+    pub pro_se: Option<ProSe>,
+    pub year_of_tape: Option<u32>,
+    /// Criminal cases: offense code of the most serious charge
+    pub nature_of_offense: Option<String>,
+    pub version: Option<u32>,
+}
+
+/// Paginated IDB response
+pub type FjcIntegratedDatabaseResponse = PaginatedResponse<FjcIntegratedDatabase>;
+
+/// Which IDB dataset a record came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DatasetSource {
+    /// Civil cases, SY 1988 to present
+    Civil,
+    /// Civil cases, SY 1970 through SY 1987
+    CivilHistorical,
+    /// Criminal defendants, FY 1996 to present
+    Criminal,
+    /// Criminal defendants, FY 1970 through FY 1995
+    CriminalHistorical,
+    /// Appellate cases, SY 1971 to present
+    Appellate,
+    /// Bankruptcy cases, FY 2008 to present
+    Bankruptcy,
+    Other(i32),
+}
+
+impl From<i32> for DatasetSource {
+    fn from(code: i32) -> Self {
+        match code {
+            1 => Self::Civil,
+            2 => Self::CivilHistorical,
+            3 => Self::Criminal,
+            4 => Self::CriminalHistorical,
+            5 => Self::Appellate,
+            6 => Self::Bankruptcy,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<DatasetSource> for i32 {
+    fn from(source: DatasetSource) -> Self {
+        match source {
+            DatasetSource::Civil => 1,
+            DatasetSource::CivilHistorical => 2,
+            DatasetSource::Criminal => 3,
+            DatasetSource::CriminalHistorical => 4,
+            DatasetSource::Appellate => 5,
+            DatasetSource::Bankruptcy => 6,
+            DatasetSource::Other(code) => code,
+        }
+    }
+}
+
+/// How the case came to the district court
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum Origin {
+    OriginalProceeding,
+    /// Removed from state court
+    Removed,
+    /// Remanded from the court of appeals
+    Remanded,
+    Reinstated,
+    /// Transferred from another district
+    Transferred,
+    /// Multidistrict litigation transferred in
+    MultidistrictLitigation,
+    /// Appeal to a district judge of a magistrate judge's decision
+    AppealFromMagistrate,
+    /// Reopened for the second to sixth time
+    Reopened(u8),
+    /// Multidistrict litigation originating in the district
+    MultidistrictLitigationOriginating,
+    Other(i32),
+}
+
+impl From<i32> for Origin {
+    fn from(code: i32) -> Self {
+        match code {
+            1 => Self::OriginalProceeding,
+            2 => Self::Removed,
+            3 => Self::Remanded,
+            4 => Self::Reinstated,
+            5 => Self::Transferred,
+            6 => Self::MultidistrictLitigation,
+            7 => Self::AppealFromMagistrate,
+            8..=12 => Self::Reopened(code as u8 - 6),
+            13 => Self::MultidistrictLitigationOriginating,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<Origin> for i32 {
+    fn from(origin: Origin) -> Self {
+        match origin {
+            Origin::OriginalProceeding => 1,
+            Origin::Removed => 2,
+            Origin::Remanded => 3,
+            Origin::Reinstated => 4,
+            Origin::Transferred => 5,
+            Origin::MultidistrictLitigation => 6,
+            Origin::AppealFromMagistrate => 7,
+            Origin::Reopened(n) => n as i32 + 6,
+            Origin::MultidistrictLitigationOriginating => 13,
+            Origin::Other(code) => code,
+        }
+    }
+}
+
+/// Basis of federal jurisdiction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum JurisdictionBasis {
+    GovernmentPlaintiff,
+    GovernmentDefendant,
+    FederalQuestion,
+    DiversityOfCitizenship,
+    LocalQuestion,
+    Other(i32),
+}
+
+impl From<i32> for JurisdictionBasis {
+    fn from(code: i32) -> Self {
+        match code {
+            1 => Self::GovernmentPlaintiff,
+            2 => Self::GovernmentDefendant,
+            3 => Self::FederalQuestion,
+            4 => Self::DiversityOfCitizenship,
+            5 => Self::LocalQuestion,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<JurisdictionBasis> for i32 {
+    fn from(jurisdiction: JurisdictionBasis) -> Self {
+        match jurisdiction {
+            JurisdictionBasis::GovernmentPlaintiff => 1,
+            JurisdictionBasis::GovernmentDefendant => 2,
+            JurisdictionBasis::FederalQuestion => 3,
+            JurisdictionBasis::DiversityOfCitizenship => 4,
+            JurisdictionBasis::LocalQuestion => 5,
+            JurisdictionBasis::Other(code) => code,
+        }
+    }
+}
+
+/// How far the case got before it was terminated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum ProceduralProgress {
+    /// Before issue joined: no court action
+    NoActionBeforeIssueJoined,
+    /// Before issue joined: order entered
+    OrderEntered,
+    /// After issue joined: no court action
+    NoActionAfterIssueJoined,
+    JudgmentOnMotion,
+    PretrialConferenceHeld,
+    DuringCourtTrial,
+    DuringJuryTrial,
+    AfterCourtTrial,
+    AfterJuryTrial,
+    /// After issue joined: other
+    OtherAfterIssueJoined,
+    /// Before issue joined: hearing held
+    HearingHeld,
+    /// Before issue joined: order decided
+    OrderDecided,
+    /// Request for trial de novo after arbitration
+    TrialDeNovoRequested,
+    Missing,
+    Other(i32),
+}
+
+impl From<i32> for ProceduralProgress {
+    fn from(code: i32) -> Self {
+        match code {
+            1 => Self::NoActionBeforeIssueJoined,
+            2 => Self::OrderEntered,
+            3 => Self::NoActionAfterIssueJoined,
+            4 => Self::JudgmentOnMotion,
+            5 => Self::PretrialConferenceHeld,
+            6 => Self::DuringCourtTrial,
+            7 => Self::DuringJuryTrial,
+            8 => Self::AfterCourtTrial,
+            9 => Self::AfterJuryTrial,
+            10 => Self::OtherAfterIssueJoined,
+            11 => Self::HearingHeld,
+            12 => Self::OrderDecided,
+            13 => Self::TrialDeNovoRequested,
+            -8 => Self::Missing,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<ProceduralProgress> for i32 {
+    fn from(progress: ProceduralProgress) -> Self {
+        match progress {
+            ProceduralProgress::NoActionBeforeIssueJoined => 1,
+            ProceduralProgress::OrderEntered => 2,
+            ProceduralProgress::NoActionAfterIssueJoined => 3,
+            ProceduralProgress::JudgmentOnMotion => 4,
+            ProceduralProgress::PretrialConferenceHeld => 5,
+            ProceduralProgress::DuringCourtTrial => 6,
+            ProceduralProgress::DuringJuryTrial => 7,
+            ProceduralProgress::AfterCourtTrial => 8,
+            ProceduralProgress::AfterJuryTrial => 9,
+            ProceduralProgress::OtherAfterIssueJoined => 10,
+            ProceduralProgress::HearingHeld => 11,
+            ProceduralProgress::OrderDecided => 12,
+            ProceduralProgress::TrialDeNovoRequested => 13,
+            ProceduralProgress::Missing => -8,
+            ProceduralProgress::Other(code) => code,
+        }
+    }
+}
+
+/// How the case was disposed of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum Disposition {
+    TransferredToAnotherDistrict,
+    RemandedToStateCourt,
+    WantOfProsecution,
+    LackOfJurisdiction,
+    Default,
+    Consent,
+    MotionBeforeTrial,
+    JuryVerdict,
+    DirectedVerdict,
+    CourtTrial,
+    MultidistrictLitigationTransfer,
+    RemandedToAgency,
+    VoluntarilyDismissed,
+    Settled,
+    /// Dismissed for another reason
+    OtherDismissal,
+    AwardOfArbitrator,
+    StayedPendingBankruptcy,
+    /// Judgment for another reason
+    OtherJudgment,
+    StatisticalClosing,
+    /// Appeal of a magistrate judge's decision affirmed
+    AppealAffirmed,
+    /// Appeal of a magistrate judge's decision denied
+    AppealDenied,
+    Missing,
+    Other(i32),
+}
+
+impl From<i32> for Disposition {
+    fn from(code: i32) -> Self {
+        match code {
+            0 => Self::TransferredToAnotherDistrict,
+            1 => Self::RemandedToStateCourt,
+            2 => Self::WantOfProsecution,
+            3 => Self::LackOfJurisdiction,
+            4 => Self::Default,
+            5 => Self::Consent,
+            6 => Self::MotionBeforeTrial,
+            7 => Self::JuryVerdict,
+            8 => Self::DirectedVerdict,
+            9 => Self::CourtTrial,
+            10 => Self::MultidistrictLitigationTransfer,
+            11 => Self::RemandedToAgency,
+            12 => Self::VoluntarilyDismissed,
+            13 => Self::Settled,
+            14 => Self::OtherDismissal,
+            15 => Self::AwardOfArbitrator,
+            16 => Self::StayedPendingBankruptcy,
+            17 => Self::OtherJudgment,
+            18 => Self::StatisticalClosing,
+            19 => Self::AppealAffirmed,
+            20 => Self::AppealDenied,
+            -8 => Self::Missing,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<Disposition> for i32 {
+    fn from(disposition: Disposition) -> Self {
+        match disposition {
+            Disposition::TransferredToAnotherDistrict => 0,
+            Disposition::RemandedToStateCourt => 1,
+            Disposition::WantOfProsecution => 2,
+            Disposition::LackOfJurisdiction => 3,
+            Disposition::Default => 4,
+            Disposition::Consent => 5,
+            Disposition::MotionBeforeTrial => 6,
+            Disposition::JuryVerdict => 7,
+            Disposition::DirectedVerdict => 8,
+            Disposition::CourtTrial => 9,
+            Disposition::MultidistrictLitigationTransfer => 10,
+            Disposition::RemandedToAgency => 11,
+            Disposition::VoluntarilyDismissed => 12,
+            Disposition::Settled => 13,
+            Disposition::OtherDismissal => 14,
+            Disposition::AwardOfArbitrator => 15,
+            Disposition::StayedPendingBankruptcy => 16,
+            Disposition::OtherJudgment => 17,
+            Disposition::StatisticalClosing => 18,
+            Disposition::AppealAffirmed => 19,
+            Disposition::AppealDenied => 20,
+            Disposition::Missing => -8,
+            Disposition::Other(code) => code,
+        }
+    }
+}
+
+/// What the judgment awarded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum NatureOfJudgement {
+    NoMonetaryAward,
+    MonetaryAwardOnly,
+    MonetaryAwardAndOther,
+    Injunction,
+    /// Forfeiture, foreclosure, condemnation, etc.
+    Forfeiture,
+    CostsOnly,
+    CostsAndAttorneyFees,
+    Missing,
+    Other(i32),
+}
+
+impl From<i32> for NatureOfJudgement {
+    fn from(code: i32) -> Self {
+        match code {
+            0 => Self::NoMonetaryAward,
+            1 => Self::MonetaryAwardOnly,
+            2 => Self::MonetaryAwardAndOther,
+            3 => Self::Injunction,
+            4 => Self::Forfeiture,
+            5 => Self::CostsOnly,
+            6 => Self::CostsAndAttorneyFees,
+            -8 => Self::Missing,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<NatureOfJudgement> for i32 {
+    fn from(nature: NatureOfJudgement) -> Self {
+        match nature {
+            NatureOfJudgement::NoMonetaryAward => 0,
+            NatureOfJudgement::MonetaryAwardOnly => 1,
+            NatureOfJudgement::MonetaryAwardAndOther => 2,
+            NatureOfJudgement::Injunction => 3,
+            NatureOfJudgement::Forfeiture => 4,
+            NatureOfJudgement::CostsOnly => 5,
+            NatureOfJudgement::CostsAndAttorneyFees => 6,
+            NatureOfJudgement::Missing => -8,
+            NatureOfJudgement::Other(code) => code,
+        }
+    }
+}
+
+/// Which side the judgment was for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum Judgment {
+    Plaintiff,
+    Defendant,
+    Both,
+    Unknown,
+    Missing,
+    Other(i32),
+}
+
+impl From<i32> for Judgment {
+    fn from(code: i32) -> Self {
+        match code {
+            1 => Self::Plaintiff,
+            2 => Self::Defendant,
+            3 => Self::Both,
+            4 => Self::Unknown,
+            -8 => Self::Missing,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<Judgment> for i32 {
+    fn from(judgment: Judgment) -> Self {
+        match judgment {
+            Judgment::Plaintiff => 1,
+            Judgment::Defendant => 2,
+            Judgment::Both => 3,
+            Judgment::Unknown => 4,
+            Judgment::Missing => -8,
+            Judgment::Other(code) => code,
+        }
+    }
+}
+
+/// Which parties appeared without counsel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum ProSe {
+    None,
+    Plaintiffs,
+    Defendants,
+    Both,
+    Missing,
+    Other(i32),
+}
+
+impl From<i32> for ProSe {
+    fn from(code: i32) -> Self {
+        match code {
+            0 => Self::None,
+            1 => Self::Plaintiffs,
+            2 => Self::Defendants,
+            3 => Self::Both,
+            -8 => Self::Missing,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<ProSe> for i32 {
+    fn from(pro_se: ProSe) -> Self {
+        match pro_se {
+            ProSe::None => 0,
+            ProSe::Plaintiffs => 1,
+            ProSe::Defendants => 2,
+            ProSe::Both => 3,
+            ProSe::Missing => -8,
+            ProSe::Other(code) => code,
+        }
+    }
+}
@@ -10,9 +10,12 @@ pub mod citations;
 pub mod courts;
 pub mod dockets;
 pub mod financial;
+pub mod fjc;
 pub mod opinions;
 pub mod parenthetical;
 pub mod people;
+pub mod recap;
+pub mod reference;
 pub mod school;
 pub mod search;
 pub mod webhooks;
@@ -27,9 +30,12 @@ pub use citations::*;
 pub use courts::*;
 pub use dockets::*;
 pub use financial::*;
+pub use fjc::*;
 pub use opinions::*;
 pub use parenthetical::*;
 pub use people::*;
+pub use recap::*;
+pub use reference::*;
 pub use school::*;
 pub use search::*;
 pub use webhooks::*;
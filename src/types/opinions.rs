@@ -1,6 +1,10 @@
 //! Opinion and OpinionCluster types
 
 use crate::types::common::{PaginatedResponse, PrecedentialStatus};
+use crate::types::courts::ApiCourt;
+use crate::types::dockets::Docket;
+use crate::types::people::ApiPerson;
+use crate::types::reference::Ref;
 use serde::{Deserialize, Serialize};
 
 /// Opinion cluster (group of related opinions)
@@ -28,9 +32,9 @@ pub struct OpinionCluster {
     pub date_blocked: Option<String>,
     pub blocked: Option<bool>,
     pub court_id: Option<String>,
-    pub court: Option<String>,
+    pub court: Option<Ref<ApiCourt>>,
     pub docket_id: Option<u32>,
-    pub docket: Option<String>,
+    pub docket: Option<Ref<Docket>>,
 }
 
 /// Opinion (individual court opinion)
@@ -38,7 +42,7 @@ pub struct OpinionCluster {
 pub struct Opinion {
     pub id: u32,
     pub author_id: Option<u32>,
-    pub author: Option<String>,
+    pub author: Option<Ref<ApiPerson>>,
     pub author_str: Option<String>,
     pub per_curiam: Option<bool>,
     pub joined_by: Option<Vec<serde_json::Value>>,
@@ -57,7 +61,7 @@ pub struct Opinion {
     pub extracted_by_ocr: Option<bool>,
     pub opinions_cited: Option<Vec<serde_json::Value>>,
     pub cluster_id: Option<u32>,
-    pub cluster: Option<String>,
+    pub cluster: Option<Ref<ApiOpinionCluster>>,
     pub absolute_url: Option<String>,
 }
 
@@ -68,9 +72,9 @@ pub struct ApiOpinionCluster {
     pub case_name: Option<String>,
     pub case_name_short: Option<String>,
     pub date_filed: Option<String>,
-    pub court: Option<String>,
+    pub court: Option<Ref<ApiCourt>>,
     pub court_id: Option<String>,
-    pub docket: Option<String>,
+    pub docket: Option<Ref<Docket>>,
     pub docket_id: Option<u32>,
     pub citation_count: Option<u32>,
     pub precedential_status: Option<PrecedentialStatus>,
@@ -1,6 +1,7 @@
 //! People (judges, attorneys, court personnel) types
 
 use crate::types::common::PaginatedResponse;
+use crate::types::school::School;
 use serde::{Deserialize, Serialize};
 
 /// Person (judge, attorney, etc.)
@@ -12,25 +13,193 @@ pub struct Person {
     pub positions: Option<Vec<Position>>,
 }
 
-/// Position (judicial position held by a person)
+/// Position held by a person (judgeship, clerkship, academic post, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub id: u32,
-    pub person: Option<u32>,
-    pub court: Option<String>,
+    pub resource_uri: Option<String>,
+    pub person: Option<String>,      // URL or person_id
+    pub court: Option<String>,       // URL or court_id
+    pub school: Option<String>,      // URL or school_id
+    pub appointer: Option<String>,   // URL or position_id of the appointing position
+    pub supervisor: Option<String>,  // URL or person_id
+    pub predecessor: Option<String>, // URL or person_id
+    pub retention_events: Option<Vec<RetentionEvent>>,
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    /// Position code, e.g. "jud" (judge) or "c-jud" (chief judge)
     pub position_type: Option<String>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub job_title: Option<String>,
+    /// 1 = private, 2 = public
+    pub sector: Option<u32>,
+    pub organization_name: Option<String>,
+    pub location_city: Option<String>,
+    pub location_state: Option<String>,
+    pub date_nominated: Option<String>,
+    pub date_elected: Option<String>,
+    pub date_recess_appointment: Option<String>,
+    pub date_referred_to_judicial_committee: Option<String>,
+    pub date_judicial_committee_action: Option<String>,
+    pub judicial_committee_action: Option<String>,
+    pub date_hearing: Option<String>,
+    pub date_confirmation: Option<String>,
+    pub date_start: Option<String>,
+    pub date_granularity_start: Option<String>,
+    pub date_termination: Option<String>,
+    pub date_granularity_termination: Option<String>,
+    /// Termination code, e.g. "ded" (death) or "retire_vol" (voluntary retirement)
+    pub termination_reason: Option<String>,
+    pub date_retirement: Option<String>,
+    pub nomination_process: Option<String>,
+    pub vote_type: Option<String>,
+    pub voice_vote: Option<bool>,
+    pub votes_yes: Option<u32>,
+    pub votes_no: Option<u32>,
+    pub votes_yes_percent: Option<f64>,
+    pub votes_no_percent: Option<f64>,
+    /// Selection code, e.g. "e_part" (partisan election) or "a_pres" (appointed by the president)
+    pub how_selected: Option<String>,
+    pub has_inferred_values: Option<bool>,
+}
+
+/// Retention event for a position (reappointment, retention vote, etc.)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionEvent {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub position: Option<String>, // URL or position_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    /// Retention code, e.g. "reapp_gov" or "elec_u"
+    pub retention_type: Option<String>,
+    pub date_retention: Option<String>,
+    pub votes_yes: Option<u32>,
+    pub votes_no: Option<u32>,
+    pub votes_yes_percent: Option<f64>,
+    pub votes_no_percent: Option<f64>,
+    pub unopposed: Option<bool>,
+    pub won: Option<bool>,
+}
+
+/// Degree a person earned at a school
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Education {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub person: Option<String>, // URL or person_id
+    pub school: Option<School>,
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    /// Degree code, e.g. "ba", "jd" or "llm"
+    pub degree_level: Option<String>,
+    pub degree_detail: Option<String>,
+    pub degree_year: Option<u32>,
+}
+
+/// Political party affiliation over a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoliticalAffiliation {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub person: Option<String>, // URL or person_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    /// Party code, e.g. "d" (Democratic) or "r" (Republican)
+    pub political_party: Option<String>,
+    /// Source code, e.g. "b" (ballot) or "a" (appointer)
+    pub source: Option<String>,
+    pub date_start: Option<String>,
+    pub date_granularity_start: Option<String>,
+    pub date_end: Option<String>,
+    pub date_granularity_end: Option<String>,
+}
+
+/// American Bar Association rating of a nominee
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbaRating {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub person: Option<String>, // URL or person_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub year_rated: Option<u32>,
+    /// Rating code, e.g. "ewq" (exceptionally well qualified) or "nq" (not qualified)
+    pub rating: Option<String>,
+}
+
+/// Source of biographical data about a person
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Source {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub person: Option<String>, // URL or person_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub url: Option<String>,
+    pub date_accessed: Option<String>,
+    pub notes: Option<String>,
 }
 
 /// API Person response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiPerson {
     pub id: u32,
+    pub resource_uri: Option<String>,
+    pub absolute_url: Option<String>,
     pub name: Option<String>,
     pub slug: Option<String>,
-    pub positions: Option<Vec<serde_json::Value>>,
+    pub name_first: Option<String>,
+    pub name_middle: Option<String>,
+    pub name_last: Option<String>,
+    pub name_suffix: Option<String>,
+    pub is_alias_of: Option<String>, // URL or person_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub date_completed: Option<String>,
+    /// Federal Judicial Center biographical database id
+    pub fjc_id: Option<u32>,
+    pub date_dob: Option<String>,
+    pub date_granularity_dob: Option<String>,
+    pub dob_city: Option<String>,
+    pub dob_state: Option<String>,
+    pub dob_country: Option<String>,
+    pub date_dod: Option<String>,
+    pub date_granularity_dod: Option<String>,
+    pub dod_city: Option<String>,
+    pub dod_state: Option<String>,
+    pub dod_country: Option<String>,
+    /// "m", "f" or "o"
+    pub gender: Option<String>,
+    /// Race codes, e.g. "w", "b", "h"
+    pub race: Option<Vec<String>>,
+    pub religion: Option<String>,
+    pub has_photo: Option<bool>,
+    pub ftm_total_received: Option<f64>,
+    pub ftm_eid: Option<String>,
+    pub positions: Option<Vec<Position>>,
+    pub educations: Option<Vec<Education>>,
+    pub political_affiliations: Option<Vec<PoliticalAffiliation>>,
+    pub aba_ratings: Option<Vec<AbaRating>>,
+    pub sources: Option<Vec<Source>>,
 }
 
 /// Paginated people response
 pub type PeopleResponse = PaginatedResponse<ApiPerson>;
+
+/// Paginated positions response
+pub type PositionsResponse = PaginatedResponse<Position>;
+
+/// Paginated retention events response
+pub type RetentionEventsResponse = PaginatedResponse<RetentionEvent>;
+
+/// Paginated educations response
+pub type EducationsResponse = PaginatedResponse<Education>;
+
+/// Paginated political affiliations response
+pub type PoliticalAffiliationsResponse = PaginatedResponse<PoliticalAffiliation>;
+
+/// Paginated ABA ratings response
+pub type AbaRatingsResponse = PaginatedResponse<AbaRating>;
+
+/// Paginated sources response
+pub type SourcesResponse = PaginatedResponse<Source>;
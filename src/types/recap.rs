@@ -0,0 +1,200 @@
+//! RECAP (PACER) types: docket entries, documents, parties and attorneys
+
+use crate::types::common::PaginatedResponse;
+use serde::{Deserialize, Serialize};
+
+/// Docket entry (one line of a PACER docket report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocketEntry {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub docket: Option<String>, // URL or docket_id
+    pub recap_documents: Option<Vec<RecapDocument>>,
+    pub tags: Option<Vec<String>>,
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub date_filed: Option<String>,
+    pub time_filed: Option<String>,
+    pub entry_number: Option<i64>,
+    pub recap_sequence_number: Option<String>,
+    pub pacer_sequence_number: Option<i64>,
+    pub description: Option<String>,
+}
+
+/// RECAP document (main document or attachment of a docket entry)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecapDocument {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub docket_entry: Option<String>, // URL or docket_entry_id
+    pub tags: Option<Vec<String>>,
+    pub absolute_url: Option<String>,
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub date_upload: Option<String>,
+    /// 1 = main document, 2 = attachment
+    pub document_type: Option<u32>,
+    pub document_number: Option<String>,
+    pub attachment_number: Option<u32>,
+    pub pacer_doc_id: Option<String>,
+    pub is_available: Option<bool>,
+    pub is_free_on_pacer: Option<bool>,
+    pub is_sealed: Option<bool>,
+    pub sha1: Option<String>,
+    pub page_count: Option<u32>,
+    pub file_size: Option<u64>,
+    pub filepath_local: Option<String>,
+    pub filepath_ia: Option<String>,
+    pub ia_upload_failure_count: Option<u32>,
+    pub thumbnail: Option<String>,
+    pub thumbnail_status: Option<u32>,
+    pub plain_text: Option<String>,
+    pub ocr_status: Option<u32>,
+    pub description: Option<String>,
+    pub acms_document_guid: Option<String>,
+}
+
+/// Party to a case (plaintiff, defendant, etc.)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Party {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub attorneys: Option<Vec<PartyAttorney>>,
+    pub party_types: Option<Vec<PartyType>>,
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub name: Option<String>,
+    pub extra_info: Option<String>,
+}
+
+/// An attorney's role for a party, as listed on the party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyAttorney {
+    pub attorney: Option<String>, // URL or attorney_id
+    pub attorney_id: Option<u32>,
+    pub date_action: Option<String>,
+    pub docket: Option<String>, // URL or docket_id
+    pub docket_id: Option<u32>,
+    pub role: Option<u32>,
+}
+
+impl PartyAttorney {
+    /// Human-readable name of the role code
+    pub fn role_name(&self) -> Option<&'static str> {
+        self.role.and_then(attorney_role_name)
+    }
+}
+
+/// A party's type in one docket (e.g. "Plaintiff"), with criminal data if any
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyType {
+    pub docket: Option<String>, // URL or docket_id
+    pub docket_id: Option<u32>,
+    pub name: Option<String>,
+    pub date_terminated: Option<String>,
+    pub extra_info: Option<String>,
+    pub highest_offense_level_opening: Option<String>,
+    pub highest_offense_level_terminated: Option<String>,
+    pub criminal_counts: Option<Vec<CriminalCount>>,
+    pub criminal_complaints: Option<Vec<CriminalComplaint>>,
+}
+
+/// Criminal count against a party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriminalCount {
+    pub name: Option<String>,
+    pub disposition: Option<String>,
+    /// 1 = pending, 2 = terminated
+    pub status: Option<u32>,
+}
+
+/// Criminal complaint against a party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriminalComplaint {
+    pub name: Option<String>,
+    pub disposition: Option<String>,
+}
+
+/// Attorney appearing in RECAP dockets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attorney {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub parties_represented: Option<Vec<AttorneyRole>>,
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub name: Option<String>,
+    pub contact_raw: Option<String>,
+    pub phone: Option<String>,
+    pub fax: Option<String>,
+    pub email: Option<String>,
+}
+
+/// A party an attorney represents, and in what role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttorneyRole {
+    pub role: Option<u32>,
+    pub docket: Option<String>, // URL or docket_id
+    pub party: Option<String>,  // URL or party_id
+    pub date_action: Option<String>,
+}
+
+impl AttorneyRole {
+    /// Human-readable name of the role code
+    pub fn role_name(&self) -> Option<&'static str> {
+        self.role.and_then(attorney_role_name)
+    }
+}
+
+/// Name of a CourtListener attorney role code
+pub fn attorney_role_name(code: u32) -> Option<&'static str> {
+    Some(match code {
+        1 => "Attorney to be noticed",
+        2 => "Lead attorney",
+        3 => "Attorney in sealed group",
+        4 => "Pro hac vice",
+        5 => "Self-terminated",
+        6 => "Terminated",
+        7 => "Suspended",
+        8 => "Inactive",
+        9 => "Disbarred",
+        10 => "Unknown",
+        _ => return None,
+    })
+}
+
+/// Lower court information for appellate dockets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OriginatingCourtInformation {
+    pub id: u32,
+    pub resource_uri: Option<String>,
+    pub assigned_to: Option<String>,    // URL or person_id
+    pub ordering_judge: Option<String>, // URL or person_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    pub docket_number: Option<String>,
+    pub assigned_to_str: Option<String>,
+    pub ordering_judge_str: Option<String>,
+    pub court_reporter: Option<String>,
+    pub date_disposed: Option<String>,
+    pub date_filed: Option<String>,
+    pub date_judgment: Option<String>,
+    pub date_judgment_eod: Option<String>,
+    pub date_filed_noa: Option<String>,
+    pub date_received_coa: Option<String>,
+}
+
+/// Paginated docket entries response
+pub type DocketEntriesResponse = PaginatedResponse<DocketEntry>;
+
+/// Paginated RECAP documents response
+pub type RecapDocumentsResponse = PaginatedResponse<RecapDocument>;
+
+/// Paginated parties response
+pub type PartiesResponse = PaginatedResponse<Party>;
+
+/// Paginated attorneys response
+pub type AttorneysResponse = PaginatedResponse<Attorney>;
+
+/// Paginated originating court information response
+pub type OriginatingCourtInformationResponse = PaginatedResponse<OriginatingCourtInformation>;
@@ -0,0 +1,196 @@
+//! Hyperlinked references to other resources
+//!
+//! CourtListener serializes related objects as hyperlinks
+//! (`https://www.courtlistener.com/api/rest/v4/dockets/4214664/`), while
+//! some payloads carry the bare id instead. `Ref<T>` accepts either and
+//! exposes the typed id of the referenced resource.
+
+use crate::types::audio::Audio;
+use crate::types::common::{AudioId, ClusterId, CourtId, DocketId, OpinionId, PersonId};
+use crate::types::courts::ApiCourt;
+use crate::types::dockets::Docket;
+use crate::types::opinions::{ApiOpinion, ApiOpinionCluster};
+use crate::types::people::ApiPerson;
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// A resource with its own list/detail endpoint
+pub trait ApiResource: DeserializeOwned {
+    /// Primary key type (e.g. `DocketId`, `CourtId`)
+    type Id: Clone + Eq + Hash + Debug + Display + FromStr + Serialize;
+
+    /// Endpoint path (e.g. "/dockets/")
+    const PATH: &'static str;
+
+    /// Primary key of this object
+    fn id(&self) -> Self::Id;
+}
+
+impl ApiResource for ApiCourt {
+    type Id = CourtId;
+    const PATH: &'static str = "/courts/";
+
+    fn id(&self) -> CourtId {
+        self.id.clone()
+    }
+}
+
+impl ApiResource for Docket {
+    type Id = DocketId;
+    const PATH: &'static str = "/dockets/";
+
+    fn id(&self) -> DocketId {
+        self.id
+    }
+}
+
+impl ApiResource for ApiOpinionCluster {
+    type Id = ClusterId;
+    const PATH: &'static str = "/clusters/";
+
+    fn id(&self) -> ClusterId {
+        self.id
+    }
+}
+
+impl ApiResource for ApiOpinion {
+    type Id = OpinionId;
+    const PATH: &'static str = "/opinions/";
+
+    fn id(&self) -> OpinionId {
+        self.id
+    }
+}
+
+impl ApiResource for ApiPerson {
+    type Id = PersonId;
+    const PATH: &'static str = "/people/";
+
+    fn id(&self) -> PersonId {
+        self.id
+    }
+}
+
+impl ApiResource for Audio {
+    type Id = AudioId;
+    const PATH: &'static str = "/audio/";
+
+    fn id(&self) -> AudioId {
+        self.id
+    }
+}
+
+/// Reference to a `T`, given as a hyperlink or a bare id
+/// Serializes back to the hyperlink when there was one
+pub struct Ref<T: ApiResource> {
+    id: T::Id,
+    url: Option<String>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: ApiResource> Ref<T> {
+    /// Reference by id
+    pub fn new(id: impl Into<T::Id>) -> Self {
+        Self {
+            id: id.into(),
+            url: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Parse a hyperlink (`.../dockets/4214664/`) or a bare id (`4214664`)
+    /// The id is the last path segment of a hyperlink
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if !value.contains('/') {
+            return Some(Self::new(value.parse::<T::Id>().ok()?));
+        }
+        let path = value.split(['?', '#']).next().unwrap_or_default();
+        let segment = path.trim_end_matches('/').rsplit('/').next()?;
+        Some(Self {
+            id: segment.parse().ok()?,
+            url: Some(value.to_string()),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Id of the referenced object
+    pub fn id(&self) -> &T::Id {
+        &self.id
+    }
+
+    /// Hyperlink, when the reference was given as one
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Endpoint of the referenced object, relative to the API base URL
+    pub fn endpoint(&self) -> String {
+        format!("{}{}/", T::PATH, crate::query::encode(&self.id.to_string()))
+    }
+}
+
+impl<T: ApiResource> Clone for Ref<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            url: self.url.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ApiResource> Debug for Ref<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ref")
+            .field("id", &self.id)
+            .field("url", &self.url)
+            .finish()
+    }
+}
+
+/// References are equal when they point at the same object
+impl<T: ApiResource> PartialEq for Ref<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T: ApiResource> Eq for Ref<T> {}
+
+impl<T: ApiResource> Hash for Ref<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T: ApiResource> Serialize for Ref<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.url {
+            Some(url) => serializer.serialize_str(url),
+            None => self.id.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T: ApiResource> Deserialize<'de> for Ref<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Id(u64),
+            Text(String),
+        }
+
+        let text = match Raw::deserialize(deserializer)? {
+            Raw::Id(id) => id.to_string(),
+            Raw::Text(text) => text,
+        };
+        Self::parse(&text)
+            .ok_or_else(|| D::Error::custom(format!("invalid {} reference: {}", T::PATH, text)))
+    }
+}
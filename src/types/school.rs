@@ -1,12 +1,22 @@
 //! School types
 
+use crate::types::common::PaginatedResponse;
 use serde::{Deserialize, Serialize};
 
 /// School (educational institution)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct School {
     pub id: u32,
+    pub resource_uri: Option<String>,
+    pub is_alias_of: Option<String>, // URL or school_id
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
     pub name: Option<String>,
+    /// IRS employer identification number
+    pub ein: Option<u32>,
     pub city: Option<String>,
     pub state: Option<String>,
 }
+
+/// Paginated schools response
+pub type SchoolsResponse = PaginatedResponse<School>;
@@ -1,7 +1,59 @@
 //! Search result types
 
+use crate::types::common::PaginatedResponse;
 use serde::{Deserialize, Serialize};
 
+/// Search result types (`type=` parameter of the search API)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SearchType {
+    /// Case law opinion clusters (default)
+    #[default]
+    #[serde(rename = "o")]
+    Opinions,
+    /// Federal cases (dockets) with up to 3 nested documents
+    #[serde(rename = "r")]
+    Recap,
+    /// Federal filing documents from PACER
+    #[serde(rename = "rd")]
+    RecapDocuments,
+    /// Federal cases (dockets) from PACER
+    #[serde(rename = "d")]
+    Dockets,
+    /// Judges
+    #[serde(rename = "p")]
+    People,
+    /// Oral argument audio files
+    #[serde(rename = "oa")]
+    OralArguments,
+}
+
+impl SearchType {
+    /// Value of the `type=` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Opinions => "o",
+            Self::Recap => "r",
+            Self::RecapDocuments => "rd",
+            Self::Dockets => "d",
+            Self::People => "p",
+            Self::OralArguments => "oa",
+        }
+    }
+
+    /// Parse a `type=` query parameter value
+    pub fn from_param(value: &str) -> Option<Self> {
+        match value {
+            "o" => Some(Self::Opinions),
+            "r" => Some(Self::Recap),
+            "rd" => Some(Self::RecapDocuments),
+            "d" => Some(Self::Dockets),
+            "p" => Some(Self::People),
+            "oa" => Some(Self::OralArguments),
+            _ => None,
+        }
+    }
+}
+
 /// Search result from CourtListener search API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
@@ -37,3 +89,382 @@ pub struct SearchResult {
     pub slug: Option<String>,
     pub absolute_url: Option<String>,
 }
+
+// --- Per-type search results ---
+//
+// Field names keep the search API's spelling (including camelCase) so that
+// re-serialized results match what CourtListener returns.
+
+/// Search result metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchMeta {
+    pub timestamp: Option<String>,
+    pub date_created: Option<String>,
+    pub score: Option<serde_json::Value>,
+}
+
+/// Opinion nested in a case law search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchOpinion {
+    pub id: u32,
+    pub author_id: Option<u32>,
+    pub cites: Option<Vec<u32>>,
+    pub download_url: Option<String>,
+    pub joined_by_ids: Option<Vec<u32>>,
+    pub local_path: Option<String>,
+    pub meta: Option<SearchMeta>,
+    pub ordering_key: Option<u32>,
+    pub per_curiam: Option<bool>,
+    pub sha1: Option<String>,
+    pub snippet: Option<String>,
+    pub r#type: Option<String>,
+}
+
+/// Case law search result (`type=o`): an opinion cluster with nested opinions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpinionClusterSearchResult {
+    pub cluster_id: u32,
+    pub absolute_url: Option<String>,
+    pub attorney: Option<String>,
+    #[serde(rename = "caseName")]
+    pub case_name: Option<String>,
+    #[serde(rename = "caseNameFull")]
+    pub case_name_full: Option<String>,
+    pub citation: Option<Vec<String>>,
+    #[serde(rename = "citeCount")]
+    pub cite_count: Option<u32>,
+    pub court: Option<String>,
+    pub court_citation_string: Option<String>,
+    pub court_id: Option<String>,
+    #[serde(rename = "dateArgued")]
+    pub date_argued: Option<String>,
+    #[serde(rename = "dateFiled")]
+    pub date_filed: Option<String>,
+    #[serde(rename = "dateReargued")]
+    pub date_reargued: Option<String>,
+    #[serde(rename = "dateReargumentDenied")]
+    pub date_reargument_denied: Option<String>,
+    #[serde(rename = "docketNumber")]
+    pub docket_number: Option<String>,
+    pub docket_id: Option<u32>,
+    pub judge: Option<String>,
+    #[serde(rename = "lexisCite")]
+    pub lexis_cite: Option<String>,
+    pub meta: Option<SearchMeta>,
+    #[serde(rename = "neutralCite")]
+    pub neutral_cite: Option<String>,
+    pub non_participating_judge_ids: Option<Vec<u32>>,
+    pub opinions: Option<Vec<SearchOpinion>>,
+    pub panel_ids: Option<Vec<u32>>,
+    pub panel_names: Option<Vec<String>>,
+    pub posture: Option<String>,
+    pub procedural_history: Option<String>,
+    pub scdb_id: Option<String>,
+    pub sibling_ids: Option<Vec<u32>>,
+    pub source: Option<String>,
+    pub status: Option<String>,
+    #[serde(rename = "suitNature")]
+    pub suit_nature: Option<String>,
+    pub syllabus: Option<String>,
+}
+
+/// RECAP document nested in a RECAP search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchRecapDocument {
+    pub id: u32,
+    pub absolute_url: Option<String>,
+    pub attachment_number: Option<u32>,
+    pub cites: Option<Vec<u32>>,
+    pub description: Option<String>,
+    pub document_number: Option<String>,
+    pub document_type: Option<String>,
+    pub entry_date_filed: Option<String>,
+    pub entry_number: Option<u32>,
+    pub filepath_local: Option<String>,
+    pub is_available: Option<bool>,
+    pub meta: Option<SearchMeta>,
+    pub pacer_doc_id: Option<String>,
+    pub page_count: Option<u32>,
+    pub short_description: Option<String>,
+    pub snippet: Option<String>,
+}
+
+/// Federal case search result (`type=r`): a docket with nested RECAP documents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecapSearchResult {
+    pub docket_id: u32,
+    pub absolute_url: Option<String>,
+    #[serde(rename = "assignedTo")]
+    pub assigned_to: Option<String>,
+    pub assigned_to_id: Option<u32>,
+    pub attorney: Option<Vec<String>>,
+    pub attorney_id: Option<Vec<u32>>,
+    #[serde(rename = "caseName")]
+    pub case_name: Option<String>,
+    pub cause: Option<String>,
+    pub chapter: Option<u32>,
+    pub court: Option<String>,
+    pub court_citation_string: Option<String>,
+    pub court_id: Option<String>,
+    #[serde(rename = "dateArgued")]
+    pub date_argued: Option<String>,
+    #[serde(rename = "dateFiled")]
+    pub date_filed: Option<String>,
+    #[serde(rename = "dateTerminated")]
+    pub date_terminated: Option<String>,
+    #[serde(rename = "docketNumber")]
+    pub docket_number: Option<String>,
+    pub docket_absolute_url: Option<String>,
+    pub firm: Option<Vec<String>>,
+    pub firm_id: Option<Vec<u32>>,
+    #[serde(rename = "jurisdictionType")]
+    pub jurisdiction_type: Option<String>,
+    #[serde(rename = "juryDemand")]
+    pub jury_demand: Option<String>,
+    pub meta: Option<SearchMeta>,
+    pub more_docs: Option<bool>,
+    pub pacer_case_id: Option<String>,
+    pub party: Option<Vec<String>>,
+    pub party_id: Option<Vec<u32>>,
+    #[serde(rename = "referredTo")]
+    pub referred_to: Option<String>,
+    pub referred_to_id: Option<u32>,
+    pub recap_documents: Option<Vec<SearchRecapDocument>>,
+    #[serde(rename = "suitNature")]
+    pub suit_nature: Option<String>,
+    pub trustee_str: Option<String>,
+}
+
+/// Federal case search result without documents (`type=d`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocketSearchResult {
+    pub docket_id: u32,
+    pub absolute_url: Option<String>,
+    #[serde(rename = "assignedTo")]
+    pub assigned_to: Option<String>,
+    pub assigned_to_id: Option<u32>,
+    pub attorney: Option<Vec<String>>,
+    pub attorney_id: Option<Vec<u32>>,
+    #[serde(rename = "caseName")]
+    pub case_name: Option<String>,
+    pub cause: Option<String>,
+    pub chapter: Option<u32>,
+    pub court: Option<String>,
+    pub court_citation_string: Option<String>,
+    pub court_id: Option<String>,
+    #[serde(rename = "dateArgued")]
+    pub date_argued: Option<String>,
+    #[serde(rename = "dateFiled")]
+    pub date_filed: Option<String>,
+    #[serde(rename = "dateTerminated")]
+    pub date_terminated: Option<String>,
+    #[serde(rename = "docketNumber")]
+    pub docket_number: Option<String>,
+    pub firm: Option<Vec<String>>,
+    pub firm_id: Option<Vec<u32>>,
+    #[serde(rename = "jurisdictionType")]
+    pub jurisdiction_type: Option<String>,
+    #[serde(rename = "juryDemand")]
+    pub jury_demand: Option<String>,
+    pub meta: Option<SearchMeta>,
+    pub pacer_case_id: Option<String>,
+    pub party: Option<Vec<String>>,
+    pub party_id: Option<Vec<u32>>,
+    #[serde(rename = "referredTo")]
+    pub referred_to: Option<String>,
+    pub referred_to_id: Option<u32>,
+    #[serde(rename = "suitNature")]
+    pub suit_nature: Option<String>,
+    pub trustee_str: Option<String>,
+}
+
+/// Federal filing document search result (`type=rd`)
+/// Flat: document fields plus the fields of its docket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecapDocumentSearchResult {
+    pub id: u32,
+    pub docket_id: Option<u32>,
+    pub absolute_url: Option<String>,
+    #[serde(rename = "assignedTo")]
+    pub assigned_to: Option<String>,
+    pub assigned_to_id: Option<u32>,
+    pub attachment_number: Option<u32>,
+    #[serde(rename = "caseName")]
+    pub case_name: Option<String>,
+    pub cause: Option<String>,
+    pub cites: Option<Vec<u32>>,
+    pub court: Option<String>,
+    pub court_citation_string: Option<String>,
+    pub court_id: Option<String>,
+    #[serde(rename = "dateFiled")]
+    pub date_filed: Option<String>,
+    #[serde(rename = "dateTerminated")]
+    pub date_terminated: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "docketNumber")]
+    pub docket_number: Option<String>,
+    pub document_number: Option<String>,
+    pub document_type: Option<String>,
+    pub entry_date_filed: Option<String>,
+    pub entry_number: Option<u32>,
+    pub filepath_local: Option<String>,
+    pub is_available: Option<bool>,
+    pub meta: Option<SearchMeta>,
+    pub pacer_doc_id: Option<String>,
+    pub page_count: Option<u32>,
+    #[serde(rename = "referredTo")]
+    pub referred_to: Option<String>,
+    pub referred_to_id: Option<u32>,
+    pub short_description: Option<String>,
+    pub snippet: Option<String>,
+    #[serde(rename = "suitNature")]
+    pub suit_nature: Option<String>,
+}
+
+/// Judicial position nested in a judge search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchPosition {
+    pub appointer: Option<String>,
+    pub court: Option<String>,
+    pub court_exact: Option<String>,
+    pub court_full_name: Option<String>,
+    pub date_confirmation: Option<String>,
+    pub date_elected: Option<String>,
+    pub date_nominated: Option<String>,
+    pub date_retirement: Option<String>,
+    pub date_start: Option<String>,
+    pub date_termination: Option<String>,
+    pub judicial_committee_action: Option<String>,
+    pub nomination_process: Option<String>,
+    pub position_type: Option<String>,
+    pub predecessor: Option<String>,
+    pub selection_method: Option<String>,
+    pub supervisor: Option<String>,
+    pub termination_reason: Option<String>,
+}
+
+/// Judge search result (`type=p`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JudgeSearchResult {
+    pub id: u32,
+    pub aba_rating: Option<Vec<String>>,
+    pub absolute_url: Option<String>,
+    pub alias_ids: Option<Vec<u32>>,
+    pub dob: Option<String>,
+    pub dob_city: Option<String>,
+    pub dob_state: Option<String>,
+    pub dob_state_id: Option<String>,
+    pub dod: Option<String>,
+    pub fjc_id: Option<u32>,
+    pub gender: Option<String>,
+    pub meta: Option<SearchMeta>,
+    pub name: Option<String>,
+    pub political_affiliation: Option<Vec<String>>,
+    pub positions: Option<Vec<SearchPosition>>,
+    pub race: Option<Vec<String>>,
+    pub religion: Option<String>,
+    pub school: Option<Vec<String>>,
+    pub snippet: Option<String>,
+}
+
+/// Oral argument search result (`type=oa`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioSearchResult {
+    pub id: u32,
+    pub absolute_url: Option<String>,
+    #[serde(rename = "caseName")]
+    pub case_name: Option<String>,
+    pub court: Option<String>,
+    pub court_citation_string: Option<String>,
+    pub court_id: Option<String>,
+    #[serde(rename = "dateArgued")]
+    pub date_argued: Option<String>,
+    #[serde(rename = "dateReargued")]
+    pub date_reargued: Option<String>,
+    #[serde(rename = "dateReargumentDenied")]
+    pub date_reargument_denied: Option<String>,
+    #[serde(rename = "docketNumber")]
+    pub docket_number: Option<String>,
+    pub docket_id: Option<u32>,
+    pub download_url: Option<String>,
+    pub duration: Option<u32>,
+    pub file_size_mp3: Option<u64>,
+    pub judge: Option<String>,
+    pub local_path: Option<String>,
+    pub meta: Option<SearchMeta>,
+    pub panel_ids: Option<Vec<u32>>,
+    pub sha1: Option<String>,
+    pub snippet: Option<String>,
+    pub source: Option<String>,
+}
+
+/// Search API response, typed by the requested search type
+/// Serializes as the underlying paginated response
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum SearchResponse {
+    Opinions(PaginatedResponse<OpinionClusterSearchResult>),
+    Recap(PaginatedResponse<RecapSearchResult>),
+    RecapDocuments(PaginatedResponse<RecapDocumentSearchResult>),
+    Dockets(PaginatedResponse<DocketSearchResult>),
+    People(PaginatedResponse<JudgeSearchResult>),
+    OralArguments(PaginatedResponse<AudioSearchResult>),
+}
+
+impl SearchResponse {
+    /// Parse a search response for the given search type
+    pub fn from_value(
+        search_type: SearchType,
+        value: serde_json::Value,
+    ) -> serde_json::Result<Self> {
+        Ok(match search_type {
+            SearchType::Opinions => Self::Opinions(serde_json::from_value(value)?),
+            SearchType::Recap => Self::Recap(serde_json::from_value(value)?),
+            SearchType::RecapDocuments => Self::RecapDocuments(serde_json::from_value(value)?),
+            SearchType::Dockets => Self::Dockets(serde_json::from_value(value)?),
+            SearchType::People => Self::People(serde_json::from_value(value)?),
+            SearchType::OralArguments => Self::OralArguments(serde_json::from_value(value)?),
+        })
+    }
+
+    /// Parse a search response body for the given search type
+    pub fn from_json(search_type: SearchType, json: &str) -> serde_json::Result<Self> {
+        Self::from_value(search_type, serde_json::from_str(json)?)
+    }
+
+    /// Search type of the results
+    pub fn search_type(&self) -> SearchType {
+        match self {
+            Self::Opinions(_) => SearchType::Opinions,
+            Self::Recap(_) => SearchType::Recap,
+            Self::RecapDocuments(_) => SearchType::RecapDocuments,
+            Self::Dockets(_) => SearchType::Dockets,
+            Self::People(_) => SearchType::People,
+            Self::OralArguments(_) => SearchType::OralArguments,
+        }
+    }
+
+    /// Total result count
+    pub fn count(&self) -> u32 {
+        match self {
+            Self::Opinions(r) => r.count,
+            Self::Recap(r) => r.count,
+            Self::RecapDocuments(r) => r.count,
+            Self::Dockets(r) => r.count,
+            Self::People(r) => r.count,
+            Self::OralArguments(r) => r.count,
+        }
+    }
+
+    /// URL of the next page, if any
+    pub fn next(&self) -> Option<&str> {
+        match self {
+            Self::Opinions(r) => r.next.as_deref(),
+            Self::Recap(r) => r.next.as_deref(),
+            Self::RecapDocuments(r) => r.next.as_deref(),
+            Self::Dockets(r) => r.next.as_deref(),
+            Self::People(r) => r.next.as_deref(),
+            Self::OralArguments(r) => r.next.as_deref(),
+        }
+    }
+}
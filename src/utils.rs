@@ -5,8 +5,7 @@ use worker::*;
 /// Sanitize error messages to prevent sensitive data leaks
 pub(crate) fn sanitize_error(text: &str) -> String {
     // Truncate long error messages
-    let truncated = if text.len() > 200 { &text[..200] } else { text };
-    truncated.to_string()
+    crate::errors::truncate_detail(text).to_string()
 }
 
 /// Create CORS preflight response for OPTIONS requests
@@ -22,7 +21,7 @@ pub(crate) fn cors_preflight_response() -> worker::Result<Response> {
     )?;
     headers.set(
         "Access-Control-Allow-Headers",
-        "Content-Type, Authorization",
+        "Content-Type, Authorization, Cache-Control",
     )?;
     headers.set("Access-Control-Max-Age", "86400")?;
     Ok(response)
@@ -30,21 +29,30 @@ pub(crate) fn cors_preflight_response() -> worker::Result<Response> {
 
 /// Create a JSON response with CORS headers
 pub(crate) fn json_response<T: serde::Serialize>(data: &T) -> worker::Result<Response> {
-    json_response_with_cache(data, None, false)
+    json_response_with_cache(data, None, crate::cache::CacheStatus::Miss, true)
 }
 
 /// Create a JSON response with CORS headers and cache headers
-/// `endpoint`: Optional endpoint path for determining cache TTL
-/// `from_cache`: Whether this response was served from cache
+/// `cache_ttl`: Optional TTL in seconds; when given, cache headers are added
+/// `status`: Whether this response was served from cache, reported in `X-Cache`
+/// `shared`: Whether shared caches may store the response
+/// Every response carries a strong `ETag`, plus `Last-Modified` when the data
+/// has a `date_modified`
 pub(crate) fn json_response_with_cache<T: serde::Serialize>(
     data: &T,
-    endpoint: Option<&str>,
-    from_cache: bool,
+    cache_ttl: Option<u64>,
+    status: crate::cache::CacheStatus,
+    shared: bool,
 ) -> worker::Result<Response> {
-    use crate::cache::{add_cache_headers, get_cache_ttl};
+    use crate::cache::conditional::{etag_for, format_http_date, last_modified_of};
+    use crate::cache::add_cache_headers;
     use crate::config::get_cors_origins;
 
-    let mut response = Response::from_json(data)?;
+    let value = serde_json::to_value(data)?;
+    let body = serde_json::to_vec(&value)?;
+    let etag = etag_for(&body);
+
+    let mut response = Response::from_bytes(body)?;
     let headers = response.headers_mut();
     headers.set("Access-Control-Allow-Origin", &get_cors_origins())?;
     headers.set(
@@ -53,19 +61,169 @@ pub(crate) fn json_response_with_cache<T: serde::Serialize>(
     )?;
     headers.set(
         "Access-Control-Allow-Headers",
-        "Content-Type, Authorization",
+        "Content-Type, Authorization, Cache-Control",
     )?;
+    headers.set("Access-Control-Expose-Headers", "ETag, Last-Modified, X-Cache")?;
     headers.set("Content-Type", "application/json")?;
+    headers.set("ETag", &etag)?;
+    if let Some(modified) = last_modified_of(&value) {
+        headers.set("Last-Modified", &format_http_date(modified))?;
+    }
 
-    // Add cache headers if endpoint is provided
-    if let Some(endpoint) = endpoint {
-        let cache_ttl = get_cache_ttl(endpoint);
-        add_cache_headers(headers, cache_ttl, from_cache)?;
+    // Add cache headers if a TTL is provided
+    if let Some(cache_ttl) = cache_ttl {
+        add_cache_headers(headers, cache_ttl, status, shared)?;
     }
 
     Ok(response)
 }
 
+/// Replace a response with `304 Not Modified` if the request's
+/// `If-None-Match` / `If-Modified-Since` show the client already has it
+/// Validators are compared against the finished response, so a 304 saves
+/// bandwidth to the client, not the cache read or upstream fetch behind it
+pub(crate) fn not_modified_or(req: &Request, response: Response) -> worker::Result<Response> {
+    use crate::cache::conditional::{is_not_modified, parse_http_date};
+
+    let request_header = |name: &str| req.headers().get(name).ok().flatten();
+    let response_header = |name: &str| response.headers().get(name).ok().flatten();
+    let not_modified = is_not_modified(
+        request_header("If-None-Match").as_deref(),
+        request_header("If-Modified-Since").as_deref(),
+        response_header("ETag").as_deref(),
+        response_header("Last-Modified")
+            .as_deref()
+            .and_then(parse_http_date),
+    );
+    if !not_modified {
+        return Ok(response);
+    }
+
+    let headers = response.headers().clone();
+    headers.delete("Content-Type")?;
+    headers.delete("Content-Length")?;
+    Ok(Response::empty()?.with_status(304).with_headers(headers))
+}
+
+/// Create a JSON response from a fetch result, mapping errors with `error_response`
+pub(crate) fn json_result<T: serde::Serialize>(
+    result: crate::errors::Result<T>,
+) -> worker::Result<Response> {
+    match result {
+        Ok(data) => json_response(&data),
+        Err(err) => error_response(&err),
+    }
+}
+
+/// Create a DRF-style JSON error response (`{"detail": ..., "<field>": [...]}`)
+/// API errors keep their upstream status and `Retry-After`; request and
+/// validation errors are 400s and other failures are 502s
+pub(crate) fn error_response(err: &crate::errors::CourtListenerError) -> worker::Result<Response> {
+    use crate::config::get_cors_origins;
+    use crate::errors::CourtListenerError;
+
+    let (status, body) = match err {
+        CourtListenerError::Api(api) => {
+            let mut body = serde_json::Map::new();
+            let detail = api.detail.clone().unwrap_or_else(|| api.to_string());
+            body.insert("detail".to_string(), sanitize_error(&detail).into());
+            for (field, messages) in &api.field_errors {
+                body.insert(field.clone(), serde_json::json!(messages));
+            }
+            (api.status, serde_json::Value::Object(body))
+        }
+        CourtListenerError::InvalidRequest(_) | CourtListenerError::Validation(_) => {
+            (400, detail_body(err))
+        }
+        CourtListenerError::RateLimited(_) => (429, detail_body(err)),
+        _ => (502, detail_body(err)),
+    };
+
+    let mut response = Response::from_json(&body)?.with_status(status);
+    let headers = response.headers_mut();
+    headers.set("Access-Control-Allow-Origin", &get_cors_origins())?;
+    headers.set("Content-Type", "application/json")?;
+    if let Some(seconds) = err.retry_after() {
+        headers.set("Retry-After", &seconds.to_string())?;
+    }
+    Ok(response)
+}
+
+/// Header reporting how many upstream attempts a response took
+pub(crate) const UPSTREAM_ATTEMPTS_HEADER: &str = "X-Upstream-Attempts";
+
+/// Create a JSON response from a cached fetch, reporting the
+/// cache status in `X-Cache` and the attempt count in `X-Upstream-Attempts`
+/// (omitted for cache hits, which make no attempts)
+/// Answers conditional requests with `304 Not Modified` once the body is
+/// fetched; a cache miss still costs an upstream call
+pub(crate) fn fetched_json_result<T: serde::Serialize>(
+    req: &Request,
+    fetched: crate::api::Fetched<T>,
+) -> worker::Result<Response> {
+    let response = match fetched.result {
+        Ok(data) => not_modified_or(
+            req,
+            json_response_with_cache(
+                &data,
+                Some(fetched.ttl),
+                fetched.cache,
+                fetched.partition.is_shared(),
+            )?,
+        )?,
+        Err(err) => {
+            let mut response = error_response(&err)?;
+            // Cached 404s (negative entries) report the hit
+            if fetched.cache == crate::cache::CacheStatus::Hit {
+                response.headers_mut().set("X-Cache", "HIT")?;
+            }
+            response
+        }
+    };
+    with_attempts(response, fetched.attempts)
+}
+
+/// Add the `X-Upstream-Attempts` header to a response
+pub(crate) fn with_attempts(mut response: Response, attempts: u32) -> worker::Result<Response> {
+    if attempts > 0 {
+        response
+            .headers_mut()
+            .set(UPSTREAM_ATTEMPTS_HEADER, &attempts.to_string())?;
+    }
+    Ok(response)
+}
+
+/// Log upstream requests that needed more than one attempt
+pub(crate) fn log_attempts(req: &Request, target: &str, attempts: u32, ok: bool) {
+    if attempts > 1 {
+        let (level, outcome) = if ok {
+            ("INFO", "succeeded")
+        } else {
+            ("WARN", "failed")
+        };
+        log_with_request_id(
+            &get_or_create_request_id(req),
+            level,
+            &format!("Upstream {} {} after {} attempts", target, outcome, attempts),
+        );
+    }
+}
+
+/// Read a non-2xx upstream response into an API error
+pub(crate) async fn upstream_error(resp: &mut Response) -> crate::errors::CourtListenerError {
+    let retry_after = resp.headers().get("Retry-After").ok().flatten();
+    let text = resp.text().await.unwrap_or_default();
+    crate::errors::CourtListenerError::Api(crate::errors::ApiError::from_response(
+        resp.status_code(),
+        &text,
+        retry_after.as_deref(),
+    ))
+}
+
+fn detail_body(err: &crate::errors::CourtListenerError) -> serde_json::Value {
+    serde_json::json!({ "detail": sanitize_error(&err.to_string()) })
+}
+
 /// Generate a request ID for tracing requests
 /// Uses the X-Request-ID header if present, otherwise generates a new one
 pub(crate) fn get_or_create_request_id(req: &Request) -> String {
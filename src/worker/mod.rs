@@ -46,7 +46,7 @@ pub async fn get_current_api_version() -> worker::Result<String> {
 pub async fn main(
     req: worker::Request,
     env: worker::Env,
-    _ctx: worker::Context,
+    ctx: worker::Context,
 ) -> worker::Result<worker::Response> {
     // Generate or retrieve request ID for tracing
     let request_id = crate::utils::get_or_create_request_id(&req);
@@ -55,7 +55,8 @@ pub async fn main(
     // Add request ID to response headers for client tracing
     // Note: We'll add this to responses in a follow-up if needed
 
-    Router::new()
+    // The worker context is shared with routes so they can schedule background work
+    Router::with_data(ctx)
         .get("/", |_req, _ctx| {
             Response::ok("CourtListener Worker API\n\nVisit /docs for API documentation")
         })
@@ -89,7 +90,7 @@ pub async fn main(
         })
         // API root - list all available APIs
         .get_async("/api", |req, ctx| async move {
-            handlers::fetch_api_root(&ctx.env, &req).await
+            handlers::fetch_api_root(&ctx.env, &ctx.data, &req).await
         })
         .options_async("/api", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
@@ -97,14 +98,14 @@ pub async fn main(
         // API endpoints - fetch from CourtListener API
         // Courts
         .get_async("/api/courts", |req, ctx| async move {
-            handlers::fetch_courts(&ctx.env, &req).await
+            handlers::fetch_courts(&ctx.env, &ctx.data, &req).await
         })
         .options_async("/api/courts", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
         })
         .get_async("/api/courts/:id", |req, ctx| async move {
             if let Some(id) = ctx.param("id") {
-                handlers::fetch_court(id, &ctx.env, &req).await
+                handlers::fetch_court(id, &ctx.env, &ctx.data, &req).await
             } else {
                 Response::error("Missing court ID", 400)
             }
@@ -114,34 +115,400 @@ pub async fn main(
         })
         // Opinions
         .get_async("/api/opinions", |req, ctx| async move {
-            handlers::fetch_opinions(&ctx.env, &req).await
+            handlers::fetch_opinions(&ctx.env, &ctx.data, &req).await
         })
         .options_async("/api/opinions", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
         })
         .get_async("/api/clusters", |req, ctx| async move {
-            handlers::fetch_opinion_clusters(&ctx.env, &req).await
+            handlers::fetch_opinion_clusters(&ctx.env, &ctx.data, &req).await
         })
         .options_async("/api/clusters", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
         })
         // People
         .get_async("/api/people", |req, ctx| async move {
-            handlers::fetch_people(&ctx.env, &req).await
+            handlers::fetch_people(&ctx.env, &ctx.data, &req).await
         })
         .options_async("/api/people", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
         })
+        .get_async("/api/people/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_person(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing person ID", 400)
+            }
+        })
+        .options_async("/api/people/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        // People DB sub-resources
+        .get_async("/api/positions", |req, ctx| async move {
+            handlers::fetch_positions(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/positions", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/positions/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_position(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing position ID", 400)
+            }
+        })
+        .options_async("/api/positions/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/retention-events", |req, ctx| async move {
+            handlers::fetch_retention_events(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/retention-events", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/retention-events/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_retention_event(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing retention event ID", 400)
+            }
+        })
+        .options_async("/api/retention-events/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/educations", |req, ctx| async move {
+            handlers::fetch_educations(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/educations", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/educations/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_education(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing education ID", 400)
+            }
+        })
+        .options_async("/api/educations/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/schools", |req, ctx| async move {
+            handlers::fetch_schools(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/schools", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/schools/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_school(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing school ID", 400)
+            }
+        })
+        .options_async("/api/schools/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/political-affiliations", |req, ctx| async move {
+            handlers::fetch_political_affiliations(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/political-affiliations", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/political-affiliations/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_political_affiliation(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing political affiliation ID", 400)
+            }
+        })
+        .options_async("/api/political-affiliations/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/aba-ratings", |req, ctx| async move {
+            handlers::fetch_aba_ratings(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/aba-ratings", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/aba-ratings/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_aba_rating(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing ABA rating ID", 400)
+            }
+        })
+        .options_async("/api/aba-ratings/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/sources", |req, ctx| async move {
+            handlers::fetch_sources(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/sources", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/sources/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_source(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing source ID", 400)
+            }
+        })
+        .options_async("/api/sources/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        // Financial disclosures and their parts
+        .get_async("/api/financial-disclosures", |req, ctx| async move {
+            handlers::fetch_financial_disclosures(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/financial-disclosures", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/financial-disclosures/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_financial_disclosure(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing financial disclosure ID", 400)
+            }
+        })
+        .options_async("/api/financial-disclosures/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/investments", |req, ctx| async move {
+            handlers::fetch_investments(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/investments", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/investments/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_investment(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing investment ID", 400)
+            }
+        })
+        .options_async("/api/investments/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/disclosure-positions", |req, ctx| async move {
+            handlers::fetch_disclosure_positions(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/disclosure-positions", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/disclosure-positions/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_disclosure_position(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing disclosure position ID", 400)
+            }
+        })
+        .options_async("/api/disclosure-positions/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/agreements", |req, ctx| async move {
+            handlers::fetch_agreements(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/agreements", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/agreements/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_agreement(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing agreement ID", 400)
+            }
+        })
+        .options_async("/api/agreements/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/non-investment-incomes", |req, ctx| async move {
+            handlers::fetch_non_investment_incomes(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/non-investment-incomes", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/non-investment-incomes/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_non_investment_income(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing non-investment income ID", 400)
+            }
+        })
+        .options_async("/api/non-investment-incomes/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/spouse-incomes", |req, ctx| async move {
+            handlers::fetch_spouse_incomes(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/spouse-incomes", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/spouse-incomes/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_spouse_income(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing spouse income ID", 400)
+            }
+        })
+        .options_async("/api/spouse-incomes/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/reimbursements", |req, ctx| async move {
+            handlers::fetch_reimbursements(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/reimbursements", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/reimbursements/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_reimbursement(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing reimbursement ID", 400)
+            }
+        })
+        .options_async("/api/reimbursements/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/gifts", |req, ctx| async move {
+            handlers::fetch_gifts(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/gifts", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/gifts/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_gift(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing gift ID", 400)
+            }
+        })
+        .options_async("/api/gifts/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/debts", |req, ctx| async move {
+            handlers::fetch_debts(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/debts", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/debts/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_debt(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing debt ID", 400)
+            }
+        })
+        .options_async("/api/debts/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
         // Dockets
         .get_async("/api/dockets", |req, ctx| async move {
-            handlers::fetch_dockets(&ctx.env, &req).await
+            handlers::fetch_dockets(&ctx.env, &ctx.data, &req).await
         })
         .options_async("/api/dockets", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
         })
+        // RECAP (PACER) docket entries, documents, parties and attorneys
+        .get_async("/api/docket-entries", |req, ctx| async move {
+            handlers::fetch_docket_entries(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/docket-entries", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/docket-entries/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_docket_entry(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing docket entry ID", 400)
+            }
+        })
+        .options_async("/api/docket-entries/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/recap-documents", |req, ctx| async move {
+            handlers::fetch_recap_documents(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/recap-documents", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/recap-documents/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_recap_document(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing RECAP document ID", 400)
+            }
+        })
+        .options_async("/api/recap-documents/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/parties", |req, ctx| async move {
+            handlers::fetch_parties(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/parties", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/parties/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_party(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing party ID", 400)
+            }
+        })
+        .options_async("/api/parties/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/attorneys", |req, ctx| async move {
+            handlers::fetch_attorneys(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/attorneys", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/attorneys/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_attorney(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing attorney ID", 400)
+            }
+        })
+        .options_async("/api/attorneys/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/originating-court-information", |req, ctx| async move {
+            handlers::fetch_originating_court_information(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/originating-court-information", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/originating-court-information/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_originating_court_information_by_id(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing originating court information ID", 400)
+            }
+        })
+        .options_async("/api/originating-court-information/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        // FJC Integrated Database
+        .get_async("/api/fjc-integrated-database", |req, ctx| async move {
+            handlers::fetch_fjc_integrated_database(&ctx.env, &ctx.data, &req).await
+        })
+        .options_async("/api/fjc-integrated-database", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
+        .get_async("/api/fjc-integrated-database/:id", |req, ctx| async move {
+            if let Some(id) = ctx.param("id") {
+                handlers::fetch_fjc_integrated_database_record(id, &ctx.env, &ctx.data, &req).await
+            } else {
+                Response::error("Missing IDB record ID", 400)
+            }
+        })
+        .options_async("/api/fjc-integrated-database/:id", |req, ctx| async move {
+            handlers::fetch_api_options(&ctx.env, &req).await
+        })
         // Search (supports both GET and POST for semantic search with embeddings)
         .get_async("/api/search", |req, ctx| async move {
-            handlers::fetch_search(&ctx.env, &req).await
+            handlers::fetch_search(&ctx.env, &ctx.data, &req).await
         })
         .post_async("/api/search", |mut req, ctx| async move {
             let body = req.text().await.ok().unwrap_or_default();
@@ -150,40 +517,45 @@ pub async fn main(
         // Note: Search API doesn't support OPTIONS requests (unlike other APIs)
         // Citations
         .get_async("/api/citations", |req, ctx| async move {
-            handlers::fetch_citations(&ctx.env, &req).await
+            handlers::fetch_citations(&ctx.env, &ctx.data, &req).await
         })
         .options_async("/api/citations", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
         })
         // Audio
         .get_async("/api/audio", |req, ctx| async move {
-            handlers::fetch_audio(&ctx.env, &req).await
+            handlers::fetch_audio(&ctx.env, &ctx.data, &req).await
         })
         .options_async("/api/audio", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
         })
         // Audio file streaming (for downloading MP3 files)
         // Usage: /api/audio/stream?url=https://... or /api/audio/stream?id=12345
+        // Supports Range requests for seeking
         .get_async("/api/audio/stream", |req, ctx| async move {
             handlers::stream_audio_file(&req, &ctx.env).await
         })
+        // HEAD returns size, type and range support without the body
+        .head_async("/api/audio/stream", |req, ctx| async move {
+            handlers::stream_audio_file(&req, &ctx.env).await
+        })
         // Alerts - Docket Alerts
         .get_async("/api/docket-alerts", |req, ctx| async move {
-            handlers::proxy_api_request(&req, &ctx.env, None).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, None).await
         })
         .post_async("/api/docket-alerts", |mut req, ctx| async move {
             let body = req.text().await.ok();
-            handlers::proxy_api_request(&req, &ctx.env, body.as_deref()).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, body.as_deref()).await
         })
         .get_async("/api/docket-alerts/:id", |req, ctx| async move {
-            handlers::proxy_api_request(&req, &ctx.env, None).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, None).await
         })
         .patch_async("/api/docket-alerts/:id", |mut req, ctx| async move {
             let body = req.text().await.ok();
-            handlers::proxy_api_request(&req, &ctx.env, body.as_deref()).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, body.as_deref()).await
         })
         .delete_async("/api/docket-alerts/:id", |req, ctx| async move {
-            handlers::proxy_api_request(&req, &ctx.env, None).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, None).await
         })
         .options_async("/api/docket-alerts", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
@@ -193,21 +565,21 @@ pub async fn main(
         })
         // Alerts - Search Alerts
         .get_async("/api/alerts", |req, ctx| async move {
-            handlers::proxy_api_request(&req, &ctx.env, None).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, None).await
         })
         .post_async("/api/alerts", |mut req, ctx| async move {
             let body = req.text().await.ok();
-            handlers::proxy_api_request(&req, &ctx.env, body.as_deref()).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, body.as_deref()).await
         })
         .get_async("/api/alerts/:id", |req, ctx| async move {
-            handlers::proxy_api_request(&req, &ctx.env, None).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, None).await
         })
         .patch_async("/api/alerts/:id", |mut req, ctx| async move {
             let body = req.text().await.ok();
-            handlers::proxy_api_request(&req, &ctx.env, body.as_deref()).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, body.as_deref()).await
         })
         .delete_async("/api/alerts/:id", |req, ctx| async move {
-            handlers::proxy_api_request(&req, &ctx.env, None).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, None).await
         })
         .options_async("/api/alerts", |req, ctx| async move {
             handlers::fetch_api_options(&ctx.env, &req).await
@@ -225,25 +597,40 @@ pub async fn main(
             let body = req.text().await.ok().unwrap_or_default();
             handlers::receive_webhook(&req, &ctx.env, &body).await
         })
+        // Cache administration (requires CACHE_ADMIN_TOKEN)
+        .get_async("/admin/cache/entry", |req, ctx| async move {
+            handlers::inspect_cache_entry(&req, &ctx.env).await
+        })
+        .delete_async("/admin/cache/entry", |req, ctx| async move {
+            handlers::delete_cache_entry(&req, &ctx.env).await
+        })
+        .post_async("/admin/cache/purge", |mut req, ctx| async move {
+            let body = req.text().await.ok().unwrap_or_default();
+            handlers::purge_cache(&req, &ctx.env, &body).await
+        })
+        .post_async("/admin/cache/warm", |mut req, ctx| async move {
+            let body = req.text().await.ok().unwrap_or_default();
+            handlers::warm_cache(&req, &ctx.env, &body).await
+        })
         // Generic proxy for any CourtListener API endpoint
         // Usage: /api/proxy/courts/ or /api/proxy/search/?q=constitution
         .get_async("/api/proxy/*path", |req, ctx| async move {
-            handlers::proxy_api_request(&req, &ctx.env, None).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, None).await
         })
         .post_async("/api/proxy/*path", |mut req, ctx| async move {
             let body = req.text().await.ok();
-            handlers::proxy_api_request(&req, &ctx.env, body.as_deref()).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, body.as_deref()).await
         })
         .put_async("/api/proxy/*path", |mut req, ctx| async move {
             let body = req.text().await.ok();
-            handlers::proxy_api_request(&req, &ctx.env, body.as_deref()).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, body.as_deref()).await
         })
         .patch_async("/api/proxy/*path", |mut req, ctx| async move {
             let body = req.text().await.ok();
-            handlers::proxy_api_request(&req, &ctx.env, body.as_deref()).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, body.as_deref()).await
         })
         .delete_async("/api/proxy/*path", |req, ctx| async move {
-            handlers::proxy_api_request(&req, &ctx.env, None).await
+            handlers::proxy_api_request(&req, &ctx.env, &ctx.data, None).await
         })
         .options_async("/api/proxy/*path", |_req, _ctx| async move {
             utils::cors_preflight_response()
@@ -0,0 +1,86 @@
+//! Tests for Range handling in the audio stream proxy
+
+use courtlistener_worker::api::audio::{cache_control, forwarded_headers, ByteRange};
+use std::collections::HashMap;
+
+fn headers(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let map: HashMap<String, String> = pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    move |name| map.get(name).cloned()
+}
+
+#[test]
+fn test_parse_byte_ranges() {
+    assert_eq!(
+        ByteRange::parse("bytes=0-1023"),
+        Some(ByteRange::From {
+            start: 0,
+            end: Some(1023)
+        })
+    );
+    assert_eq!(
+        ByteRange::parse(" Bytes = 500- "),
+        Some(ByteRange::From {
+            start: 500,
+            end: None
+        })
+    );
+    assert_eq!(ByteRange::parse("bytes=-500"), Some(ByteRange::Suffix(500)));
+}
+
+#[test]
+fn test_unsupported_ranges_are_rejected() {
+    for header in [
+        "bytes=0-99,200-299",
+        "items=0-10",
+        "bytes=10-5",
+        "bytes=-0",
+        "bytes=-",
+        "bytes=a-b",
+        "bytes=+1-2",
+        "0-10",
+    ] {
+        assert_eq!(ByteRange::parse(header), None, "{}", header);
+    }
+}
+
+#[test]
+fn test_range_round_trips() {
+    for header in ["bytes=0-1023", "bytes=500-", "bytes=-500"] {
+        assert_eq!(ByteRange::parse(header).unwrap().to_string(), header);
+    }
+}
+
+#[test]
+fn test_forwarded_headers() {
+    let forwarded = forwarded_headers(headers(&[
+        ("Range", "bytes=0-1023"),
+        ("If-Range", "\"abc\""),
+        ("Cookie", "session=1"),
+    ]));
+    assert_eq!(
+        forwarded,
+        vec![
+            ("Range", "bytes=0-1023".to_string()),
+            ("If-Range", "\"abc\"".to_string()),
+        ]
+    );
+
+    // If-Range means nothing without a range
+    assert!(forwarded_headers(headers(&[("If-Range", "\"abc\"")])).is_empty());
+    // Unsupported ranges fall back to the full file
+    assert!(forwarded_headers(headers(&[
+        ("Range", "bytes=0-1,5-9"),
+        ("If-Range", "\"abc\"")
+    ]))
+    .is_empty());
+}
+
+#[test]
+fn test_only_full_responses_are_public() {
+    assert_eq!(cache_control(200), "public, max-age=86400");
+    assert_eq!(cache_control(206), "private, no-store");
+    assert_eq!(cache_control(416), "private, no-store");
+}
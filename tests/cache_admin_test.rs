@@ -0,0 +1,62 @@
+//! Tests for cache purge and inspection helpers
+
+use courtlistener_worker::cache::{
+    generate_cache_key, inspect_entry, purge_prefix, put_entry, resource_prefix, CacheBackend,
+    MemoryCache,
+};
+
+#[tokio::test]
+async fn test_purge_resource_removes_every_variant() {
+    let cache = MemoryCache::default();
+    let detail = generate_cache_key("/opinions/12/", None);
+    let with_fields = generate_cache_key("/opinions/12/", Some("fields=id"));
+    let other = generate_cache_key("/opinions/123/", None);
+    for key in [&detail, &with_fields, &other] {
+        put_entry(&cache, key, "{}", 600, 0).await;
+    }
+    put_entry(&cache, &format!("{}|user:abc", detail), "{}", 600, 0).await;
+
+    let purged = purge_prefix(&cache, &resource_prefix("opinions", "12"))
+        .await
+        .unwrap();
+    assert_eq!(purged, 3);
+    assert_eq!(cache.list("").await.unwrap(), vec![other]);
+}
+
+#[tokio::test]
+async fn test_purge_endpoint_prefix() {
+    let cache = MemoryCache::default();
+    put_entry(&cache, &generate_cache_key("/courts/", None), "{}", 600, 0).await;
+    put_entry(
+        &cache,
+        &generate_cache_key("/courts/scotus/", None),
+        "{}",
+        600,
+        0,
+    )
+    .await;
+    put_entry(&cache, &generate_cache_key("/people/", None), "{}", 600, 0).await;
+
+    let prefix = generate_cache_key("/courts/", None);
+    assert_eq!(purge_prefix(&cache, &prefix).await.unwrap(), 2);
+    assert_eq!(cache.len(), 1);
+}
+
+#[tokio::test]
+async fn test_inspect_entry() {
+    let cache = MemoryCache::default();
+    put_entry(&cache, "courts", r#"{"count":1}"#, 600, 60).await;
+    cache.put("legacy", b"{}", 600).await.unwrap();
+
+    let info = inspect_entry(&cache, "courts").await.unwrap().unwrap();
+    assert_eq!(info.state, "fresh");
+    assert_eq!(info.ttl, Some(600));
+    assert_eq!(info.max_stale, Some(60));
+    assert!(info.age.unwrap() <= 1);
+
+    let legacy = inspect_entry(&cache, "legacy").await.unwrap().unwrap();
+    assert_eq!(legacy.state, "legacy");
+    assert_eq!(legacy.size, 2);
+
+    assert_eq!(inspect_entry(&cache, "missing").await.unwrap(), None);
+}
@@ -0,0 +1,143 @@
+//! Tests for the portable cache backends
+
+use courtlistener_worker::cache::{
+    get_entry, lookup_entry, put_entry, CacheBackend, CacheBackendKind, CacheLookup, MemoryCache,
+    NoopCache,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Store an entry that was cached `age` seconds ago
+async fn put_aged_entry(cache: &MemoryCache, key: &str, age: u64, ttl: u64, max_stale: u64) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let entry = serde_json::json!({
+        "data": "{}",
+        "timestamp": now - age,
+        "ttl": ttl,
+        "max_stale": max_stale,
+    });
+    cache
+        .put(key, entry.to_string().as_bytes(), 3600)
+        .await
+        .unwrap();
+}
+
+#[test]
+fn test_memory_cache_expires_entries() {
+    let cache = MemoryCache::new(10);
+    cache.put_at("courts", b"[]", 60, 1_000);
+
+    assert_eq!(cache.get_at("courts", 60_999), Some(b"[]".to_vec()));
+    assert_eq!(cache.get_at("courts", 61_000), None);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_memory_cache_evicts_least_recently_used() {
+    let cache = MemoryCache::new(2);
+    cache.put_at("a", b"1", 60, 0);
+    cache.put_at("b", b"2", 60, 0);
+    // Reading "a" makes "b" the eviction candidate
+    assert!(cache.get_at("a", 0).is_some());
+    cache.put_at("c", b"3", 60, 0);
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get_at("a", 0).is_some());
+    assert!(cache.get_at("b", 0).is_none());
+    assert!(cache.get_at("c", 0).is_some());
+}
+
+#[tokio::test]
+async fn test_memory_cache_backend_round_trip() {
+    let cache = MemoryCache::default();
+    cache.put("dockets:court=scotus", b"{}", 600).await.unwrap();
+    assert_eq!(
+        cache.get("dockets:court=scotus").await.unwrap(),
+        Some(b"{}".to_vec())
+    );
+
+    cache.delete("dockets:court=scotus").await.unwrap();
+    assert_eq!(cache.get("dockets:court=scotus").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_entries_round_trip_and_accept_legacy_values() {
+    let cache = MemoryCache::default();
+    put_entry(&cache, "courts", r#"{"count":1}"#, 600, 0).await;
+    assert_eq!(
+        get_entry(&cache, "courts").await.as_deref(),
+        Some(r#"{"count":1}"#)
+    );
+
+    // Values written before entries carried metadata are returned as-is
+    cache.put("legacy", br#"{"count":2}"#, 600).await.unwrap();
+    assert_eq!(
+        get_entry(&cache, "legacy").await.as_deref(),
+        Some(r#"{"count":2}"#)
+    );
+}
+
+#[tokio::test]
+async fn test_lookup_serves_stale_entries_until_hard_expiry() {
+    let cache = MemoryCache::default();
+    put_aged_entry(&cache, "fresh", 100, 300, 600).await;
+    put_aged_entry(&cache, "stale", 500, 300, 600).await;
+    put_aged_entry(&cache, "expired", 1000, 300, 600).await;
+
+    assert_eq!(
+        lookup_entry(&cache, "fresh").await,
+        CacheLookup::Fresh("{}".to_string())
+    );
+    assert_eq!(
+        lookup_entry(&cache, "stale").await,
+        CacheLookup::Stale("{}".to_string())
+    );
+    assert_eq!(get_entry(&cache, "stale").await, None);
+    assert_eq!(lookup_entry(&cache, "expired").await, CacheLookup::Miss);
+    // Entries past the hard expiry are removed
+    assert_eq!(cache.get("expired").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_entries_without_stale_bound_expire_at_ttl() {
+    let cache = MemoryCache::default();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    // Entries written before max_stale existed
+    let entry = serde_json::json!({"data": "{}", "timestamp": now - 400, "ttl": 300});
+    cache
+        .put("old", entry.to_string().as_bytes(), 3600)
+        .await
+        .unwrap();
+
+    assert_eq!(lookup_entry(&cache, "old").await, CacheLookup::Miss);
+}
+
+#[tokio::test]
+async fn test_noop_cache_never_hits() {
+    put_entry(&NoopCache, "courts", "{}", 600, 0).await;
+    assert_eq!(get_entry(&NoopCache, "courts").await, None);
+}
+
+#[test]
+fn test_backend_kind_parse() {
+    assert_eq!(CacheBackendKind::parse("KV"), Some(CacheBackendKind::Kv));
+    assert_eq!(
+        CacheBackendKind::parse("cache-api"),
+        Some(CacheBackendKind::CacheApi)
+    );
+    assert_eq!(
+        CacheBackendKind::parse(" memory "),
+        Some(CacheBackendKind::Memory)
+    );
+    assert_eq!(
+        CacheBackendKind::parse("none"),
+        Some(CacheBackendKind::None)
+    );
+    assert_eq!(CacheBackendKind::parse("redis"), None);
+    assert_eq!(CacheBackendKind::default().as_str(), "kv");
+}
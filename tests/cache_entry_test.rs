@@ -0,0 +1,113 @@
+//! Tests for compressed and chunked cache entries
+
+use courtlistener_worker::cache::{
+    delete_entry, get_entry, inspect_entry, lookup_entry, purge_prefix, put_entry_with,
+    CacheBackend, CacheLookup, ContentEncoding, EntryStorage, MemoryCache,
+};
+
+/// An opinion-like payload that compresses well
+fn large_opinion() -> String {
+    let text = "The judgment of the Court of Appeals is affirmed. ".repeat(2_000);
+    serde_json::json!({ "id": 1, "plain_text": text, "html": format!("<p>{}</p>", text) })
+        .to_string()
+}
+
+#[test]
+fn test_encodings_round_trip() {
+    let data = large_opinion();
+    for encoding in [
+        ContentEncoding::Identity,
+        ContentEncoding::Gzip,
+        ContentEncoding::Brotli,
+    ] {
+        let encoded = encoding.encode(data.as_bytes()).unwrap();
+        assert_eq!(encoding.decode(&encoded).unwrap(), data.as_bytes());
+    }
+    assert_eq!(
+        ContentEncoding::parse("brotli"),
+        Some(ContentEncoding::Brotli)
+    );
+    assert_eq!(
+        ContentEncoding::parse("none"),
+        Some(ContentEncoding::Identity)
+    );
+    assert!(ContentEncoding::Gzip.decode(b"not gzip").is_err());
+}
+
+#[tokio::test]
+async fn test_compressed_entries_are_decoded_transparently() {
+    let cache = MemoryCache::default();
+    let data = large_opinion();
+    for (key, encoding) in [
+        ("gz", ContentEncoding::Gzip),
+        ("br", ContentEncoding::Brotli),
+    ] {
+        let storage = EntryStorage {
+            encoding,
+            ..EntryStorage::default()
+        };
+        put_entry_with(&cache, key, &data, 600, 0, &storage).await;
+        let stored = cache.get(key).await.unwrap().unwrap();
+        assert!(stored.len() < data.len() / 10);
+        assert_eq!(get_entry(&cache, key).await.as_deref(), Some(data.as_str()));
+
+        let info = inspect_entry(&cache, key).await.unwrap().unwrap();
+        assert_eq!(info.encoding, Some(encoding.as_str()));
+        assert_eq!(info.data_size, Some(data.len()));
+    }
+
+    // Small values are not worth compressing
+    put_entry_with(&cache, "small", "{}", 600, 0, &EntryStorage::default()).await;
+    let info = inspect_entry(&cache, "small").await.unwrap().unwrap();
+    assert_eq!(info.encoding, Some("identity"));
+}
+
+#[tokio::test]
+async fn test_large_entries_are_chunked() {
+    let cache = MemoryCache::default();
+    let data = "x".repeat(4_500);
+    let storage = EntryStorage {
+        encoding: ContentEncoding::Identity,
+        chunk_size: 1_000,
+    };
+    put_entry_with(&cache, "v1:opinions/1/", &data, 600, 0, &storage).await;
+
+    assert_eq!(cache.len(), 5);
+    assert_eq!(
+        lookup_entry(&cache, "v1:opinions/1/").await,
+        CacheLookup::Fresh(data.clone())
+    );
+    let info = inspect_entry(&cache, "v1:opinions/1/")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(info.chunks, Some(5));
+
+    // Chunks are purged with their entry but not counted
+    assert_eq!(purge_prefix(&cache, "v1:opinions/").await.unwrap(), 1);
+    assert!(cache.is_empty());
+
+    put_entry_with(&cache, "v1:opinions/1/", &data, 600, 0, &storage).await;
+    delete_entry(&cache, "v1:opinions/1/").await.unwrap();
+    assert!(cache.is_empty());
+}
+
+#[tokio::test]
+async fn test_entries_with_missing_chunks_are_misses() {
+    let cache = MemoryCache::default();
+    let storage = EntryStorage {
+        encoding: ContentEncoding::Identity,
+        chunk_size: 1_000,
+    };
+    put_entry_with(&cache, "key", &"y".repeat(2_500), 600, 0, &storage).await;
+    let chunk = cache
+        .list("key#chunk:")
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    cache.delete(&chunk).await.unwrap();
+
+    assert_eq!(lookup_entry(&cache, "key").await, CacheLookup::Miss);
+}
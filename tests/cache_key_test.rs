@@ -0,0 +1,59 @@
+//! Tests for canonical cache keys
+
+use courtlistener_worker::cache::{canonical_query, generate_cache_key, CACHE_KEY_VERSION};
+
+#[test]
+fn test_key_has_version_prefix() {
+    let prefix = format!("v{}:", CACHE_KEY_VERSION);
+    assert_eq!(
+        generate_cache_key("/courts/", None),
+        format!("{}courts/", prefix)
+    );
+    assert_eq!(
+        generate_cache_key("/courts/", Some("")),
+        format!("{}courts/", prefix)
+    );
+}
+
+#[test]
+fn test_param_order_does_not_matter() {
+    assert_eq!(
+        generate_cache_key("/dockets/", Some("court=scotus&order_by=-id")),
+        generate_cache_key("/dockets/", Some("order_by=-id&court=scotus"))
+    );
+}
+
+#[test]
+fn test_no_op_params_are_dropped() {
+    assert_eq!(
+        canonical_query("page_size=20&q=privacy&page=1&court="),
+        "q=privacy"
+    );
+    assert_eq!(
+        canonical_query("page_size=50&q=privacy"),
+        "page_size=50&q=privacy"
+    );
+}
+
+#[test]
+fn test_encoding_is_normalized() {
+    let expected = canonical_query("case_name=Roe%20v.%20Wade");
+    assert_eq!(expected, "case_name=Roe%20v.%20Wade");
+    assert_eq!(canonical_query("case_name=Roe+v.+Wade"), expected);
+    assert_eq!(canonical_query("case_name=Roe%20v%2E%20Wade"), expected);
+    assert_eq!(canonical_query("q=%zz"), "q=%25zz");
+}
+
+#[test]
+fn test_long_queries_use_stable_hash() {
+    let query = format!("q={}", "constitution ".repeat(30));
+    let key = generate_cache_key("/search/", Some(&query));
+    let (prefix, digest) = key.rsplit_once(":q:").unwrap();
+    assert_eq!(prefix, format!("v{}:search/", CACHE_KEY_VERSION));
+    assert_eq!(digest.len(), 64);
+    // The digest does not depend on the build or process
+    assert_eq!(
+        digest,
+        "d7f2fab7f7dda27edd3ea9e52c5635126f79b4a5ffe6065db31dec5d7a100319"
+    );
+}
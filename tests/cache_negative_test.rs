@@ -0,0 +1,82 @@
+//! Tests for negative caching of 404s and empty results
+
+use courtlistener_worker::cache::{
+    get_entry, inspect_entry, is_empty_result, lookup_entry, put_negative_entry, CacheBackend,
+    CacheConfig, CacheLookup, MemoryCache,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[tokio::test]
+async fn test_negative_entries_keep_their_status() {
+    let cache = MemoryCache::default();
+    let body = r#"{"detail":"No Court matches the given query."}"#;
+    put_negative_entry(&cache, "v1:courts/nope/", 404, body, 60).await;
+
+    assert_eq!(
+        lookup_entry(&cache, "v1:courts/nope/").await,
+        CacheLookup::Negative {
+            status: 404,
+            body: body.to_string()
+        }
+    );
+    // Negative entries are not data
+    assert_eq!(get_entry(&cache, "v1:courts/nope/").await, None);
+    let info = inspect_entry(&cache, "v1:courts/nope/")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(info.status, Some(404));
+    assert_eq!(info.max_stale, Some(0));
+}
+
+#[tokio::test]
+async fn test_expired_negative_entries_are_misses() {
+    let cache = MemoryCache::default();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let header = serde_json::json!({
+        "timestamp": now - 120,
+        "ttl": 60,
+        "max_stale": 0,
+        "encoding": "identity",
+        "size": 2,
+        "chunks": 1,
+        "id": "",
+        "status": 404,
+    });
+    let value = format!("clce1\n{}\n{{}}", header);
+    cache
+        .put("v1:dockets/1/", value.as_bytes(), 3600)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        lookup_entry(&cache, "v1:dockets/1/").await,
+        CacheLookup::Miss
+    );
+    assert_eq!(cache.get("v1:dockets/1/").await.unwrap(), None);
+}
+
+#[test]
+fn test_empty_result_detection() {
+    assert!(is_empty_result(
+        r#"{"count":0,"next":null,"previous":null,"results":[]}"#
+    ));
+    // Cursor-paginated endpoints omit the count
+    assert!(is_empty_result(r#"{"next":null,"results":[]}"#));
+    assert!(!is_empty_result(r#"{"count":1,"results":[{"id":1}]}"#));
+    assert!(!is_empty_result(r#"{"id":1,"results":"n/a"}"#));
+    assert!(!is_empty_result("not json"));
+}
+
+#[test]
+fn test_negative_ttl_is_capped_by_endpoint_ttl() {
+    let mut config = CacheConfig::default();
+    assert_eq!(config.negative_ttl_for("/courts/nope/"), 60);
+    config.negative_ttl = 900;
+    assert_eq!(config.negative_ttl_for("/search/"), 300);
+    config.negative_ttl = 0;
+    assert_eq!(config.negative_ttl_for("/courts/"), 0);
+}
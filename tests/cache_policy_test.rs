@@ -0,0 +1,72 @@
+//! Tests for the per-endpoint TTL policy and request cache directives
+
+use courtlistener_worker::cache::{
+    get_cache_ttl, parse_ttl_rules, CacheConfig, CacheDirectives, TtlPolicyConfig, TtlRule,
+};
+
+#[test]
+fn test_default_policy_ttls() {
+    assert_eq!(get_cache_ttl("/search/"), 300);
+    assert_eq!(get_cache_ttl("/dockets/123/"), 900);
+    assert_eq!(get_cache_ttl("/docket-alerts/"), 900);
+    assert_eq!(get_cache_ttl("/opinions/?court=scotus"), 1800);
+    assert_eq!(get_cache_ttl("/clusters/"), 1800);
+    assert_eq!(get_cache_ttl("/courts/"), 3600);
+    assert_eq!(get_cache_ttl("/people/7/"), 3600);
+    assert_eq!(get_cache_ttl("/audio/"), 600);
+    // Patterns match whole paths, not substrings
+    assert_eq!(get_cache_ttl("/opinions-cited/"), 600);
+}
+
+#[test]
+fn test_rule_patterns() {
+    let rule = TtlRule::new("/opinions/*/", 60);
+    assert!(rule.matches("/opinions/42/"));
+    assert!(rule.matches("opinions/42/?fields=id"));
+    assert!(!rule.matches("/opinions/"));
+    assert!(!rule.matches("/clusters/42/"));
+}
+
+#[test]
+fn test_configured_rules_take_precedence_and_respect_max_ttl() {
+    let mut config = CacheConfig::default();
+    config.apply_policy(
+        TtlPolicyConfig::from_json(
+            r#"{"default_ttl": 120, "max_ttl": 2000,
+                "rules": [{"pattern": "/opinions/*/", "ttl": 86400}]}"#,
+        )
+        .unwrap(),
+    );
+    assert_eq!(config.ttl_for("/opinions/42/"), 2000);
+    assert_eq!(config.ttl_for("/opinions/"), 1800);
+    assert_eq!(config.ttl_for("/courts/"), 2000);
+    assert_eq!(config.ttl_for("/audio/"), 120);
+
+    assert!(TtlPolicyConfig::from_json(r#"{"rules": [{"pattern": 1}]}"#).is_err());
+}
+
+#[test]
+fn test_parse_ttl_rules() {
+    let rules = parse_ttl_rules("/search/*=120, /opinions/*=7200,bogus,/x/=abc");
+    assert_eq!(
+        rules,
+        vec![
+            TtlRule::new("/search/*", 120),
+            TtlRule::new("/opinions/*", 7200)
+        ]
+    );
+}
+
+#[test]
+fn test_cache_directives() {
+    let parse = CacheDirectives::parse;
+    assert_eq!(parse(None, None), CacheDirectives::default());
+    assert!(parse(Some("no-cache"), None).no_cache);
+    assert!(parse(Some("max-age=0"), None).no_cache);
+    assert!(!parse(Some("max-age=60"), None).no_cache);
+    let directives = parse(Some("No-Store, no-cache"), None);
+    assert!(directives.no_store && directives.no_cache);
+    assert!(parse(None, Some("no-cache")).no_cache);
+    // Cache-Control takes precedence over Pragma
+    assert!(!parse(Some("max-age=60"), Some("no-cache")).no_cache);
+}
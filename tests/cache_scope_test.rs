@@ -0,0 +1,77 @@
+//! Tests for auth-aware cache partitioning
+
+use courtlistener_worker::cache::scope::credential_hash;
+use courtlistener_worker::cache::{CacheConfig, CachePartition, CacheScope, UserScopedCaching};
+
+#[test]
+fn test_endpoint_scopes() {
+    assert_eq!(CacheScope::of("/courts/"), CacheScope::Public);
+    assert_eq!(CacheScope::of("/search/"), CacheScope::Public);
+    assert_eq!(CacheScope::of("/docket-alerts/"), CacheScope::User);
+    assert_eq!(CacheScope::of("/alerts/12/"), CacheScope::User);
+    assert_eq!(CacheScope::of("alerts"), CacheScope::User);
+}
+
+#[test]
+fn test_query_does_not_hide_user_scope() {
+    assert_eq!(CacheScope::of("/docket-alerts?page=2"), CacheScope::User);
+    assert_eq!(CacheScope::of("tags?name=x#top"), CacheScope::User);
+    assert_eq!(CacheScope::of("/courts?id=scotus"), CacheScope::Public);
+
+    let config = CacheConfig::default();
+    let partition = CachePartition::for_request("/alerts?page=2", None, Some("t"), &config);
+    assert_eq!(
+        partition,
+        CachePartition::Private(credential_hash(Some("t")))
+    );
+}
+
+#[test]
+fn test_public_endpoints_share_entries() {
+    let config = CacheConfig::default();
+    let partition = CachePartition::for_request("/courts/", None, Some("token"), &config);
+    assert_eq!(partition, CachePartition::Shared);
+    assert_eq!(partition.key("v1:courts/").as_deref(), Some("v1:courts/"));
+    assert!(partition.is_shared());
+}
+
+#[test]
+fn test_user_scoped_endpoints_are_partitioned_by_credential() {
+    let config = CacheConfig::default();
+    let a = CachePartition::for_request("/alerts/", None, Some("token-a"), &config);
+    let b = CachePartition::for_request("/alerts/", None, Some("token-b"), &config);
+    assert_eq!(a, CachePartition::Private(credential_hash(Some("token-a"))));
+    assert_ne!(a.key("v1:alerts/"), b.key("v1:alerts/"));
+    assert!(!a.key("v1:alerts/").unwrap().contains("token-a"));
+    assert!(!a.is_shared());
+}
+
+#[test]
+fn test_user_scoped_bypass_mode() {
+    let config = CacheConfig {
+        user_scoped: UserScopedCaching::Bypass,
+        ..CacheConfig::default()
+    };
+    let partition = CachePartition::for_request("/docket-alerts/", None, Some("t"), &config);
+    assert_eq!(partition, CachePartition::Bypass);
+    assert_eq!(partition.key("v1:docket-alerts/"), None);
+}
+
+#[test]
+fn test_caller_authorization_bypasses_unless_configured() {
+    let auth = Some("Token caller");
+    let config = CacheConfig::default();
+    assert_eq!(
+        CachePartition::for_request("/courts/", auth, Some("t"), &config),
+        CachePartition::Bypass
+    );
+
+    let config = CacheConfig {
+        cache_authorized: true,
+        ..CacheConfig::default()
+    };
+    assert_eq!(
+        CachePartition::for_request("/courts/", auth, Some("t"), &config),
+        CachePartition::Private(credential_hash(auth))
+    );
+}
@@ -0,0 +1,67 @@
+//! Tests for single-flight coalescing of upstream fetches
+
+use courtlistener_worker::cache::{Coalescing, SingleFlight};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[tokio::test]
+async fn test_concurrent_fetches_share_one_call() {
+    let flights: SingleFlight<String> = SingleFlight::new();
+    let calls = Rc::new(Cell::new(0));
+    let fetch = |calls: Rc<Cell<u32>>| {
+        move || async move {
+            calls.set(calls.get() + 1);
+            // Let the other callers arrive while this fetch is in flight
+            tokio::task::yield_now().await;
+            "opinion".to_string()
+        }
+    };
+
+    let (a, b, c) = futures_util::join!(
+        flights.run("v1:opinions/1/", fetch(calls.clone())),
+        flights.run("v1:opinions/1/", fetch(calls.clone())),
+        flights.run("v1:opinions/2/", fetch(calls.clone())),
+    );
+
+    assert_eq!(calls.get(), 2);
+    assert_eq!(a, ("opinion".to_string(), false));
+    assert_eq!(b, ("opinion".to_string(), true));
+    assert!(!c.1);
+    assert_eq!(flights.in_flight(), 0);
+}
+
+#[tokio::test]
+async fn test_later_fetches_start_a_new_flight() {
+    let flights: SingleFlight<u32> = SingleFlight::new();
+    let (first, _) = flights.run("key", || async { 1 }).await;
+    let (second, joined) = flights.run("key", || async { 2 }).await;
+
+    assert_eq!((first, second), (1, 2));
+    assert!(!joined);
+}
+
+#[tokio::test]
+async fn test_joiners_fetch_on_their_own_when_the_originator_is_dropped() {
+    let flights: SingleFlight<u32> = SingleFlight::new();
+    let mut originator = Box::pin(flights.run("key", futures_util::future::pending));
+    assert!(futures_util::poll!(&mut originator).is_pending());
+
+    let mut joiner = Box::pin(flights.run("key", || async { 2 }));
+    assert!(futures_util::poll!(&mut joiner).is_pending());
+
+    drop(originator);
+    assert_eq!(flights.in_flight(), 0);
+    assert_eq!(joiner.await, (2, false));
+}
+
+#[test]
+fn test_coalescing_parse() {
+    assert_eq!(Coalescing::parse("off"), Some(Coalescing::Off));
+    assert_eq!(Coalescing::parse(" Isolate "), Some(Coalescing::Isolate));
+    assert_eq!(
+        Coalescing::parse("durable-object"),
+        Some(Coalescing::DurableObject)
+    );
+    assert_eq!(Coalescing::parse("redis"), None);
+    assert_eq!(Coalescing::default(), Coalescing::Isolate);
+}
@@ -1,12 +1,87 @@
 //! Common utilities for integration tests
+#![allow(dead_code)]
+
+use courtlistener_worker::api::{HttpRequest, HttpResponse, HttpTransport};
+use courtlistener_worker::errors::Result;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::time::Duration;
 
 // Use the same API base URL as the worker (v4 path, but API version is 4.3.0)
 pub const API_BASE_URL: &str = "https://www.courtlistener.com/api/rest/v4";
 
 /// Helper to fetch JSON from CourtListener API
-pub async fn fetch_json(endpoint: &str) -> Result<String, Box<dyn std::error::Error>> {
+pub async fn fetch_json(endpoint: &str) -> std::result::Result<String, Box<dyn std::error::Error>> {
     let url = format!("{}{}", API_BASE_URL, endpoint);
     let response = reqwest::get(&url).await?;
     let text = response.text().await?;
     Ok(text)
 }
+
+/// In-memory transport that replies with queued responses, in order, and
+/// records the requests it was sent and the waits it was asked for
+pub struct QueuedTransport {
+    responses: RefCell<VecDeque<HttpResponse>>,
+    pub requests: RefCell<Vec<HttpRequest>>,
+    pub sleeps: RefCell<Vec<Duration>>,
+}
+
+impl QueuedTransport {
+    /// Reply with `(status, body)` pairs
+    pub fn new(responses: Vec<(u16, &str)>) -> Self {
+        Self::with_retry_after(
+            responses
+                .into_iter()
+                .map(|(status, body)| (status, None, body))
+                .collect(),
+        )
+    }
+
+    /// Reply with `(status, Retry-After, body)` triples
+    pub fn with_retry_after(responses: Vec<(u16, Option<&str>, &str)>) -> Self {
+        let responses = responses
+            .into_iter()
+            .map(|(status, retry_after, body)| HttpResponse {
+                status,
+                headers: retry_after
+                    .map(|v| vec![("Retry-After".to_string(), v.to_string())])
+                    .unwrap_or_default(),
+                body: body.to_string(),
+            })
+            .collect();
+        Self {
+            responses: RefCell::new(responses),
+            requests: RefCell::new(Vec::new()),
+            sleeps: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Number of requests sent
+    pub fn sent(&self) -> usize {
+        self.requests.borrow().len()
+    }
+
+    /// URLs of the requests sent, in order
+    pub fn urls(&self) -> Vec<String> {
+        self.requests
+            .borrow()
+            .iter()
+            .map(|request| request.url.clone())
+            .collect()
+    }
+}
+
+impl HttpTransport for QueuedTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.requests.borrow_mut().push(request);
+        Ok(self
+            .responses
+            .borrow_mut()
+            .pop_front()
+            .expect("unexpected request"))
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        self.sleeps.borrow_mut().push(duration);
+    }
+}
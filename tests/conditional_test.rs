@@ -0,0 +1,82 @@
+//! Tests for ETag / Last-Modified conditional request helpers
+
+use courtlistener_worker::cache::conditional::{
+    etag_for, format_http_date, is_not_modified, last_modified_of, parse_http_date, parse_iso8601,
+};
+use serde_json::json;
+
+#[test]
+fn test_etag_is_strong_and_stable() {
+    let etag = etag_for(br#"{"id":1}"#);
+    assert!(etag.starts_with('"') && etag.ends_with('"'));
+    assert_eq!(etag.len(), 34);
+    assert_eq!(etag, etag_for(br#"{"id":1}"#));
+    assert_ne!(etag, etag_for(br#"{"id":2}"#));
+}
+
+#[test]
+fn test_http_dates_round_trip() {
+    assert_eq!(
+        format_http_date(784_111_777),
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+        Some(784_111_777)
+    );
+    assert_eq!(
+        format_http_date(951_782_400),
+        "Tue, 29 Feb 2000 00:00:00 GMT"
+    );
+    assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+}
+
+#[test]
+fn test_parse_iso8601_offsets() {
+    assert_eq!(parse_iso8601("1994-11-06T08:49:37Z"), Some(784_111_777));
+    assert_eq!(
+        parse_iso8601("1994-11-06T00:49:37.123456-08:00"),
+        Some(784_111_777)
+    );
+    assert_eq!(parse_iso8601("1994-11-06T08:49:37"), Some(784_111_777));
+    assert_eq!(parse_iso8601("1994-11-06"), Some(784_080_000));
+    assert_eq!(parse_iso8601("not a date"), None);
+}
+
+#[test]
+fn test_last_modified_from_resource_or_results() {
+    let detail = json!({"id": 1, "date_modified": "1994-11-06T08:49:37Z"});
+    assert_eq!(last_modified_of(&detail), Some(784_111_777));
+
+    let page = json!({"results": [
+        {"date_modified": "1994-11-05T08:49:37Z"},
+        {"date_modified": "1994-11-06T08:49:37Z"},
+        {"id": 3}
+    ]});
+    assert_eq!(last_modified_of(&page), Some(784_111_777));
+    assert_eq!(last_modified_of(&json!({"count": 0})), None);
+}
+
+#[test]
+fn test_if_none_match() {
+    let etag = Some("\"abc\"");
+    assert!(is_not_modified(Some("\"abc\""), None, etag, None));
+    assert!(is_not_modified(Some("\"x\", W/\"abc\""), None, etag, None));
+    assert!(is_not_modified(Some("*"), None, etag, None));
+    assert!(!is_not_modified(Some("\"x\""), None, etag, None));
+}
+
+#[test]
+fn test_if_modified_since() {
+    let since = Some("Sun, 06 Nov 1994 08:49:37 GMT");
+    assert!(is_not_modified(None, since, None, Some(784_111_777)));
+    assert!(!is_not_modified(None, since, None, Some(784_111_778)));
+    assert!(!is_not_modified(None, since, None, None));
+    // If-None-Match wins over If-Modified-Since
+    assert!(!is_not_modified(
+        Some("\"x\""),
+        since,
+        Some("\"abc\""),
+        Some(0)
+    ));
+}
@@ -0,0 +1,94 @@
+//! Tests for structured CourtListener API errors
+
+use courtlistener_worker::errors::{truncate_detail, ApiError, CourtListenerError};
+
+#[test]
+fn test_detail_body() {
+    let error = ApiError::from_response(
+        404,
+        r#"{"detail": "No Docket matches the given query."}"#,
+        None,
+    );
+    assert_eq!(error.status, 404);
+    assert_eq!(
+        error.detail.as_deref(),
+        Some("No Docket matches the given query.")
+    );
+    assert!(error.field_errors.is_empty());
+}
+
+#[test]
+fn test_field_errors() {
+    let body = r#"{
+        "court": ["Select a valid choice. That choice is not one of the available choices."],
+        "non_field_errors": "Invalid filter",
+        "id__range": {"0": ["Enter a number."]}
+    }"#;
+    let error = ApiError::from_response(400, body, None);
+    assert_eq!(error.detail, None);
+    assert_eq!(error.field_errors.len(), 3);
+    assert_eq!(
+        error.field_errors["non_field_errors"],
+        vec!["Invalid filter"]
+    );
+    assert!(error.field_errors["court"][0].starts_with("Select a valid choice"));
+    assert!(error
+        .to_string()
+        .starts_with("API returned 400; court: Select"));
+}
+
+#[test]
+fn test_throttled_with_retry_after() {
+    let body = r#"{"detail": "Request was throttled. Expected available in 42 seconds."}"#;
+    let err = CourtListenerError::Api(ApiError::from_response(429, body, Some("42")));
+    assert!(err.is_rate_limited());
+    assert!(!err.is_not_found());
+    assert_eq!(err.retry_after(), Some(42));
+    assert_eq!(err.status(), Some(429));
+}
+
+#[test]
+fn test_http_date_retry_after_is_ignored() {
+    let error = ApiError::from_response(503, "", Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+    assert_eq!(error.retry_after, None);
+    assert_eq!(error.detail, None);
+}
+
+#[test]
+fn test_auth_classification() {
+    for status in [401, 403] {
+        let err = CourtListenerError::Api(ApiError::from_response(
+            status,
+            r#"{"detail": "Invalid token."}"#,
+            None,
+        ));
+        assert!(err.is_auth());
+    }
+    assert!(!CourtListenerError::Http("timeout".to_string()).is_auth());
+    assert_eq!(
+        CourtListenerError::Http("timeout".to_string()).status(),
+        None
+    );
+}
+
+#[test]
+fn test_non_json_body_is_truncated() {
+    let body = "x".repeat(1000);
+    let error = ApiError::from_response(502, &body, None);
+    assert_eq!(error.detail.unwrap().len(), 200);
+}
+
+#[test]
+fn test_multi_byte_detail_is_truncated_by_chars() {
+    // Over 200 bytes, but under 200 characters
+    let short = "é€".repeat(75);
+    assert_eq!(truncate_detail(&short), short);
+
+    let long = "§€".repeat(300);
+    let truncated = truncate_detail(&long);
+    assert_eq!(truncated.chars().count(), 200);
+    assert!(long.starts_with(truncated));
+
+    let error = ApiError::from_response(502, &long, None);
+    assert_eq!(error.detail.unwrap().chars().count(), 200);
+}
@@ -0,0 +1,177 @@
+//! Tests for the transport-agnostic CourtListenerClient
+//! Uses an in-memory transport, so no network access is required
+
+mod common;
+
+use common::QueuedTransport;
+use courtlistener_worker::api::HttpMethod;
+use courtlistener_worker::errors::{CourtListenerError, Result};
+use courtlistener_worker::{CourtListenerClient, CourtsResponse, PrayAndPayWebhookPayload};
+
+const COURTS_JSON: &str = r#"{
+    "count": 1,
+    "next": null,
+    "previous": null,
+    "results": [{"id": "scotus", "name": "Supreme Court"}]
+}"#;
+
+#[tokio::test]
+async fn test_fetch_json_builds_url_and_headers() {
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, COURTS_JSON)]))
+        .with_base_url("https://example.test/api/rest/v4/")
+        .with_token("secret");
+
+    let courts: CourtsResponse = client
+        .fetch_json("/courts/", Some("jurisdiction=F"))
+        .await
+        .unwrap();
+    assert_eq!(courts.results[0].id, "scotus");
+
+    let requests = client.transport().requests.borrow();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, HttpMethod::Get);
+    assert_eq!(
+        requests[0].url,
+        "https://example.test/api/rest/v4/courts/?jurisdiction=F"
+    );
+    assert!(requests[0]
+        .headers
+        .iter()
+        .any(|(k, v)| k == "Authorization" && v == "Token secret"));
+}
+
+#[tokio::test]
+async fn test_fetch_json_without_token() {
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, COURTS_JSON)]))
+        .with_base_url("https://example.test");
+    assert!(!client.has_token());
+
+    let _: CourtsResponse = client.fetch_json("/courts/", None).await.unwrap();
+
+    let requests = client.transport().requests.borrow();
+    assert_eq!(requests[0].url, "https://example.test/courts/");
+    assert!(!requests[0]
+        .headers
+        .iter()
+        .any(|(k, _)| k == "Authorization"));
+}
+
+#[tokio::test]
+async fn test_fetch_json_non_success_status() {
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(
+        404,
+        r#"{"detail":"Not found."}"#,
+    )]));
+
+    let result: Result<CourtsResponse> = client.fetch_json("/courts/nope/", None).await;
+    match result {
+        Err(err @ CourtListenerError::Api(_)) => {
+            assert!(err.is_not_found());
+            assert!(err.to_string().contains("Not found."));
+        }
+        other => panic!("expected API error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_fetch_json_validated_rejects_invalid() {
+    let body = r#"{"id": 0, "date_created": "", "status": 3, "recap_document": 0}"#;
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, body)]));
+
+    let result: Result<PrayAndPayWebhookPayload> =
+        client.fetch_json_validated("/prayers/1/", None).await;
+    assert!(matches!(result, Err(CourtListenerError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_resource_get_uses_detail_path() {
+    let body = r#"{"id": "scotus", "name": "Supreme Court"}"#;
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, body)]))
+        .with_base_url("https://example.test");
+
+    let court = client.courts().get("scotus").await.unwrap();
+    assert_eq!(court.id, "scotus");
+    assert_eq!(
+        client.transport().requests.borrow()[0].url,
+        "https://example.test/courts/scotus/"
+    );
+}
+
+#[tokio::test]
+async fn test_resource_list_with_query() {
+    let body = r#"{"count": 1, "next": null, "previous": null, "results": [{"id": 42}]}"#;
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, body)]))
+        .with_base_url("https://example.test");
+
+    let dockets = client
+        .dockets()
+        .list_with(&[("court", "scotus"), ("case_name", "Roe v. Wade")])
+        .await
+        .unwrap();
+    assert_eq!(dockets.results[0].id, 42);
+    assert_eq!(
+        client.transport().requests.borrow()[0].url,
+        "https://example.test/dockets/?court=scotus&case_name=Roe%20v.%20Wade"
+    );
+}
+
+#[tokio::test]
+async fn test_resource_paths() {
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, "{}")]));
+    assert_eq!(client.courts().path(), "/courts/");
+    assert_eq!(client.clusters().path(), "/clusters/");
+    assert_eq!(client.opinions().path(), "/opinions/");
+    assert_eq!(client.people().path(), "/people/");
+    assert_eq!(client.audio().path(), "/audio/");
+    assert_eq!(client.opinions_cited().path(), "/opinions-cited/");
+}
+
+#[tokio::test]
+async fn test_resource_list_with_filter() {
+    use courtlistener_worker::query::{CourtField, CourtFilter, Order};
+
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, COURTS_JSON)]))
+        .with_base_url("https://example.test");
+
+    let filter = CourtFilter::new()
+        .exact(CourtField::Jurisdiction, "F")
+        .order_by(CourtField::DateModified, Order::Desc);
+    client.courts().list_with(&filter).await.unwrap();
+    assert_eq!(
+        client.transport().requests.borrow()[0].url,
+        "https://example.test/courts/?jurisdiction=F&order_by=-date_modified"
+    );
+}
+
+#[tokio::test]
+async fn test_search_rejects_invalid_query_before_sending() {
+    use courtlistener_worker::query::SearchQuery;
+    use courtlistener_worker::SearchType;
+
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, COURTS_JSON)]));
+    let query = SearchQuery::new()
+        .search_type(SearchType::Recap)
+        .q("privacy")
+        .semantic(true);
+
+    assert!(client.search().query(&query).await.is_err());
+    assert!(client.transport().requests.borrow().is_empty());
+}
+
+#[tokio::test]
+async fn test_search_results_are_typed_by_query_type() {
+    use courtlistener_worker::query::SearchQuery;
+    use courtlistener_worker::{SearchResponse, SearchType};
+
+    let body = r#"{"count": 1, "next": null, "previous": null,
+        "results": [{"id": 7, "caseName": "Roe v. Wade", "duration": 3600}]}"#;
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, body)]));
+    let query = SearchQuery::new()
+        .search_type(SearchType::OralArguments)
+        .q("abortion");
+
+    match client.search().query(&query).await.unwrap() {
+        SearchResponse::OralArguments(page) => assert_eq!(page.results[0].duration, Some(3600)),
+        other => panic!("unexpected search type {:?}", other.search_type()),
+    }
+}
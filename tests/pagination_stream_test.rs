@@ -0,0 +1,91 @@
+//! Tests for auto-paginating streams over PaginatedResponse
+
+mod common;
+
+use common::QueuedTransport;
+use courtlistener_worker::api::RetryPolicy;
+use courtlistener_worker::{CourtListenerClient, PaginatedResponse, PaginationOptions};
+use futures_util::StreamExt;
+
+const PAGE_1: &str = r#"{
+    "count": "https://www.courtlistener.com/api/rest/v4/opinions/?count=on",
+    "next": "https://www.courtlistener.com/api/rest/v4/opinions/?cursor=cD0y&court=scotus",
+    "previous": null,
+    "results": [{"id": 1}, {"id": 2}]
+}"#;
+
+const PAGE_2: &str = r#"{
+    "next": null,
+    "previous": "https://www.courtlistener.com/api/rest/v4/opinions/?cursor=cj0x&court=scotus",
+    "results": [{"id": 3}]
+}"#;
+
+fn client(responses: Vec<(u16, &str)>) -> CourtListenerClient<QueuedTransport> {
+    CourtListenerClient::new(QueuedTransport::new(responses)).with_base_url("https://proxy.test")
+}
+
+#[tokio::test]
+async fn test_stream_follows_cursor_links() {
+    let client = client(vec![(200, PAGE_1), (200, PAGE_2)]);
+
+    let ids: Vec<u32> = client
+        .opinions()
+        .stream_with(&[("court", "scotus")], PaginationOptions::new())
+        .map(|opinion| opinion.unwrap().id)
+        .collect()
+        .await;
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    // Next links are rebased onto the client's base URL
+    let urls = client.transport().urls();
+    assert_eq!(
+        urls,
+        vec![
+            "https://proxy.test/opinions/?court=scotus",
+            "https://proxy.test/opinions/?cursor=cD0y&court=scotus",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_stream_max_items_stops_fetching() {
+    let client = client(vec![(200, PAGE_1), (200, PAGE_2)]);
+
+    let ids: Vec<u32> = client
+        .opinions()
+        .stream(PaginationOptions::new().max_items(2))
+        .map(|opinion| opinion.unwrap().id)
+        .collect()
+        .await;
+    assert_eq!(ids, vec![1, 2]);
+    assert_eq!(client.transport().sent(), 1);
+}
+
+#[tokio::test]
+async fn test_pages_max_pages() {
+    let client = client(vec![(200, PAGE_1), (200, PAGE_2)]);
+
+    let pages: Vec<PaginatedResponse<serde_json::Value>> = client
+        .pages("/opinions/", None, PaginationOptions::new().max_pages(1))
+        .map(|page| page.unwrap())
+        .collect()
+        .await;
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].count, 0);
+    assert_eq!(pages[0].results.len(), 2);
+}
+
+#[tokio::test]
+async fn test_stream_stops_after_error() {
+    let client =
+        client(vec![(200, PAGE_1), (500, "Server Error")]).with_retry_policy(RetryPolicy::none());
+
+    let results: Vec<_> = client
+        .opinions()
+        .stream(PaginationOptions::new())
+        .collect()
+        .await;
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok() && results[1].is_ok());
+    assert!(results[2].is_err());
+}
@@ -0,0 +1,76 @@
+//! Tests for the typed filter and ordering query builder
+
+use courtlistener_worker::query::*;
+
+#[test]
+fn test_exact_and_related_fields() {
+    let filter = DocketFilter::new()
+        .exact(DocketField::Court(CourtField::Jurisdiction), "F")
+        .exact(DocketField::AssignedTo(PersonField::NameLast), "Ginsburg");
+    assert_eq!(
+        filter.to_query(),
+        "court__jurisdiction=F&assigned_to__name_last=Ginsburg"
+    );
+}
+
+#[test]
+fn test_deeply_related_fields() {
+    let filter = OpinionFilter::new().exact(
+        OpinionField::Cluster(ClusterField::Docket(DocketField::Court(CourtField::Id))),
+        "scotus",
+    );
+    assert_eq!(filter.to_query(), "cluster__docket__court__id=scotus");
+}
+
+#[test]
+fn test_lookups() {
+    let filter = ClusterFilter::new()
+        .range(ClusterField::Id, 500, 1000)
+        .gte(ClusterField::DateFiled, "2020-01-01")
+        .lt(ClusterField::CitationCount, 10)
+        .is_in(
+            ClusterField::PrecedentialStatus,
+            ["Published", "Unpublished"],
+        )
+        .is_null(ClusterField::ScdbId, false);
+    assert_eq!(
+        filter.to_query(),
+        "id__range=500,1000&date_filed__gte=2020-01-01&citation_count__lt=10\
+         &precedential_status__in=Published,Unpublished&scdb_id__isnull=false"
+    );
+}
+
+#[test]
+fn test_ordering_fields_and_omit() {
+    let filter = CourtFilter::new()
+        .order_by(CourtField::Position, Order::Asc)
+        .order_by(CourtField::DateModified, Order::Desc)
+        .fields([CourtField::Id, CourtField::FullName])
+        .omit([CourtField::Url]);
+    assert_eq!(
+        filter.to_query(),
+        "order_by=position,-date_modified&fields=id,full_name&omit=url"
+    );
+}
+
+#[test]
+fn test_values_are_url_encoded() {
+    let filter = DocketFilter::new()
+        .icontains(DocketField::CaseName, "Roe & Wade")
+        .param("q", "a=b");
+    assert_eq!(
+        filter.to_query(),
+        "case_name__icontains=Roe%20%26%20Wade&q=a%3Db"
+    );
+}
+
+#[test]
+fn test_empty_filter() {
+    assert_eq!(AudioFilter::new().to_query(), "");
+    assert_eq!(
+        CitationFilter::new()
+            .exact(CitationField::CitedOpinion(OpinionField::Id), 42)
+            .to_query(),
+        "cited_opinion__id=42"
+    );
+}
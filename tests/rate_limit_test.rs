@@ -0,0 +1,94 @@
+//! Tests for the token bucket rate limiter
+
+mod common;
+
+use common::QueuedTransport;
+use courtlistener_worker::api::rate_limit::{bucket_key, TokenBucket};
+use courtlistener_worker::api::{RateLimitConfig, RateLimitMode, RateLimiter};
+use courtlistener_worker::CourtListenerClient;
+use std::time::Duration;
+
+/// 2-token burst refilling one token per second
+fn config() -> RateLimitConfig {
+    RateLimitConfig::new().capacity(2).refill_per_hour(3600)
+}
+
+#[test]
+fn test_bucket_spends_and_refills() {
+    let config = config();
+    let mut bucket = TokenBucket::full(&config, 0);
+    assert_eq!(bucket.try_take(&config, 0), Ok(1));
+    assert_eq!(bucket.try_take(&config, 0), Ok(0));
+    assert_eq!(
+        bucket.try_take(&config, 250),
+        Err(Duration::from_millis(750))
+    );
+    assert_eq!(bucket.remaining(&config, 1000), 1);
+    assert_eq!(bucket.try_take(&config, 1000), Ok(0));
+    // Refill never exceeds capacity
+    assert_eq!(bucket.remaining(&config, 60_000), 2);
+}
+
+#[test]
+fn test_limiter_buckets_are_per_key() {
+    let limiter = RateLimiter::new(config());
+    assert!(limiter.try_take_at("a", 0).is_ok());
+    assert!(limiter.try_take_at("a", 0).is_ok());
+    assert!(limiter.try_take_at("a", 0).is_err());
+    assert!(limiter.try_take_at("b", 0).is_ok());
+    // Clones share state
+    assert!(limiter.clone().try_take_at("b", 0).is_ok());
+    assert!(limiter.try_take_at("b", 0).is_err());
+}
+
+#[test]
+fn test_bucket_key_hides_token() {
+    let key = bucket_key(Some("secret-token"));
+    assert_eq!(key.len(), 32);
+    assert!(!key.contains("secret"));
+    assert_eq!(key, bucket_key(Some("secret-token")));
+    assert_eq!(bucket_key(None), "anonymous");
+}
+
+#[tokio::test]
+async fn test_client_rejects_when_budget_is_spent() {
+    let client = CourtListenerClient::new(QueuedTransport::new(vec![(200, "{}"); 2]))
+        .with_token("t")
+        .with_rate_limiter(RateLimiter::new(config()));
+
+    for _ in 0..2 {
+        let _: serde_json::Value = client.fetch_json("/courts/", None).await.unwrap();
+    }
+    assert_eq!(client.rate_limit_remaining(), Some(0));
+
+    let err = client
+        .fetch_json::<serde_json::Value>("/courts/", None)
+        .await
+        .unwrap_err();
+    assert!(err.is_rate_limited());
+    assert_eq!(err.retry_after(), Some(1));
+    assert_eq!(client.transport().sent(), 2);
+}
+
+#[tokio::test]
+async fn test_queue_mode_waits_for_a_token() {
+    let limiter = RateLimiter::new(config().capacity(1).mode(RateLimitMode::Queue {
+        max_wait: Duration::from_secs(5),
+    }));
+    // Spend the only token well into the future, so the client has to queue
+    let far_future = u64::MAX / 2;
+    let _ = limiter.try_take_at("anonymous", far_future);
+
+    let client =
+        CourtListenerClient::new(QueuedTransport::new(Vec::new())).with_rate_limiter(limiter);
+    let result = client
+        .fetch_json::<serde_json::Value>("/courts/", None)
+        .await;
+
+    // The mock clock never advances, so the queue gives up after max_wait
+    assert!(result.unwrap_err().is_rate_limited());
+    let sleeps = client.transport().sleeps.borrow();
+    assert!(!sleeps.is_empty());
+    assert!(sleeps.iter().sum::<Duration>() <= Duration::from_secs(5));
+    assert_eq!(client.transport().sent(), 0);
+}
@@ -0,0 +1,80 @@
+//! Tests for URL-or-id references and their resolution through the client
+
+mod common;
+
+use common::QueuedTransport;
+use courtlistener_worker::{ApiCourt, ApiPerson, Audio, CourtListenerClient, Docket, Ref};
+
+fn client(bodies: Vec<&str>) -> CourtListenerClient<QueuedTransport> {
+    let responses = bodies.into_iter().map(|body| (200, body)).collect();
+    CourtListenerClient::new(QueuedTransport::new(responses)).with_base_url("https://proxy.test")
+}
+
+#[test]
+fn test_refs_deserialize_from_urls_and_ids() {
+    let audio: Audio = serde_json::from_str(
+        r#"{
+            "id": 1,
+            "docket": "https://www.courtlistener.com/api/rest/v4/dockets/4214664/",
+            "court": "scotus",
+            "judges": [
+                "https://www.courtlistener.com/api/rest/v4/people/1213/",
+                2776
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let docket = audio.docket.unwrap();
+    assert_eq!(*docket.id(), 4214664);
+    assert!(docket.url().is_some());
+    assert_eq!(audio.court.unwrap().id(), "scotus");
+    let judges: Vec<u32> = audio.judges.unwrap().iter().map(|r| *r.id()).collect();
+    assert_eq!(judges, vec![1213, 2776]);
+
+    // Hyperlinks serialize back unchanged, bare ids as ids
+    let value = serde_json::to_value(Ref::<Docket>::new(7u32)).unwrap();
+    assert_eq!(value, 7);
+    assert_eq!(
+        serde_json::to_value(&docket).unwrap(),
+        "https://www.courtlistener.com/api/rest/v4/dockets/4214664/"
+    );
+
+    let invalid = serde_json::from_str::<Ref<Docket>>(r#""https://example.com/dockets/abc/""#);
+    assert!(invalid.is_err());
+}
+
+#[tokio::test]
+async fn test_resolve_fetches_the_referenced_object() {
+    let client = client(vec![r#"{"id": "scotus", "name": "Supreme Court"}"#]);
+    let court = Ref::<ApiCourt>::parse("https://www.courtlistener.com/api/rest/v4/courts/scotus/")
+        .unwrap()
+        .resolve(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(court.name, Some("Supreme Court".to_string()));
+    assert_eq!(
+        client.transport().urls()[0],
+        "https://proxy.test/courts/scotus/"
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_refs_batches_with_id_in() {
+    let client = client(vec![
+        r#"{"count": 2, "next": null, "previous": null,
+            "results": [{"id": 1213, "name": "Ruth Bader Ginsburg"}, {"id": 2776}]}"#,
+        r#"{"count": 1, "next": null, "previous": null, "results": [{"id": 9}]}"#,
+    ]);
+    let refs: Vec<Ref<ApiPerson>> = [1213u32, 2776, 1213, 9].into_iter().map(Ref::new).collect();
+
+    let people = client.resolve_refs(&refs, Some(2)).await.unwrap();
+
+    assert_eq!(people.len(), 3);
+    assert_eq!(people[&1213].name, Some("Ruth Bader Ginsburg".to_string()));
+    let urls = client.transport().urls();
+    assert_eq!(urls.len(), 2);
+    assert!(urls[0].contains("id__in=1213,2776"));
+    assert!(urls[1].contains("id__in=9"));
+}
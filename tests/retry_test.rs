@@ -0,0 +1,150 @@
+//! Tests for the retry policy and its use by CourtListenerClient
+
+mod common;
+
+use common::QueuedTransport;
+use courtlistener_worker::api::{
+    HttpMethod, HttpRequest, RateLimitConfig, RateLimiter, RetryPolicy,
+};
+use courtlistener_worker::CourtListenerClient;
+use std::time::Duration;
+
+const OK_JSON: &str = r#"{"count": 0, "next": null, "previous": null, "results": []}"#;
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .base_delay(Duration::from_millis(100))
+        .jitter(false)
+}
+
+#[test]
+fn test_exponential_backoff_is_capped() {
+    let policy = policy().max_delay(Duration::from_millis(350));
+    assert_eq!(policy.delay(1, None, 0.0), Some(Duration::from_millis(100)));
+    assert_eq!(policy.delay(2, None, 0.0), Some(Duration::from_millis(200)));
+    assert_eq!(policy.delay(3, None, 0.0), Some(Duration::from_millis(350)));
+}
+
+#[test]
+fn test_jitter_stays_within_half_to_full_backoff() {
+    let policy = policy().jitter(true);
+    assert_eq!(policy.delay(2, None, 0.0), Some(Duration::from_millis(100)));
+    assert_eq!(policy.delay(2, None, 1.0), Some(Duration::from_millis(200)));
+}
+
+#[test]
+fn test_retry_after_overrides_backoff_within_max_delay() {
+    let policy = policy().max_delay(Duration::from_secs(10));
+    assert_eq!(policy.delay(1, Some(3), 0.0), Some(Duration::from_secs(3)));
+    assert_eq!(policy.delay(1, Some(3600), 0.0), None);
+}
+
+#[test]
+fn test_only_idempotent_methods_are_retried() {
+    let policy = RetryPolicy::new();
+    assert!(policy.is_retryable_method(HttpMethod::Get));
+    assert!(policy.is_retryable_method(HttpMethod::Put));
+    assert!(policy.is_retryable_method(HttpMethod::Delete));
+    assert!(!policy.is_retryable_method(HttpMethod::Post));
+    assert!(!policy.is_retryable_method(HttpMethod::Patch));
+}
+
+#[tokio::test]
+async fn test_client_retries_server_errors() {
+    let transport = QueuedTransport::with_retry_after(vec![
+        (503, None, "Service Unavailable"),
+        (502, None, "Bad Gateway"),
+        (200, None, OK_JSON),
+    ]);
+    let client = CourtListenerClient::new(transport).with_retry_policy(policy());
+
+    let value: serde_json::Value = client.fetch_json("/courts/", None).await.unwrap();
+    assert_eq!(value["count"], 0);
+    assert_eq!(client.transport().sent(), 3);
+    assert_eq!(
+        *client.transport().sleeps.borrow(),
+        vec![Duration::from_millis(100), Duration::from_millis(200)]
+    );
+}
+
+#[tokio::test]
+async fn test_client_honours_retry_after() {
+    let transport =
+        QueuedTransport::with_retry_after(vec![(429, Some("2"), "{}"), (200, None, OK_JSON)]);
+    let client = CourtListenerClient::new(transport).with_retry_policy(policy());
+
+    let _: serde_json::Value = client.fetch_json("/courts/", None).await.unwrap();
+    assert_eq!(
+        *client.transport().sleeps.borrow(),
+        vec![Duration::from_secs(2)]
+    );
+}
+
+#[tokio::test]
+async fn test_client_gives_up_after_max_attempts() {
+    let transport = QueuedTransport::with_retry_after(vec![
+        (500, None, "{}"),
+        (500, None, "{}"),
+        (500, None, "{}"),
+    ]);
+    let client = CourtListenerClient::new(transport).with_retry_policy(policy());
+
+    let err = client
+        .fetch_json::<serde_json::Value>("/courts/", None)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(500));
+    assert_eq!(client.transport().sent(), 3);
+}
+
+#[tokio::test]
+async fn test_client_does_not_retry_client_errors_or_posts() {
+    let transport =
+        QueuedTransport::with_retry_after(vec![(404, None, r#"{"detail": "Not found."}"#)]);
+    let client = CourtListenerClient::new(transport).with_retry_policy(policy());
+    assert!(client
+        .fetch_json::<serde_json::Value>("/courts/nope/", None)
+        .await
+        .unwrap_err()
+        .is_not_found());
+
+    let transport = QueuedTransport::with_retry_after(vec![(503, None, "{}")]);
+    let client = CourtListenerClient::new(transport).with_retry_policy(policy());
+    let retried = client
+        .send(HttpRequest::new(
+            HttpMethod::Post,
+            "https://example.test/alerts/",
+        ))
+        .await;
+    assert_eq!(retried.attempts, 1);
+    assert_eq!(retried.result.unwrap().status, 503);
+}
+
+#[tokio::test]
+async fn test_every_attempt_takes_a_rate_limit_token() {
+    let transport =
+        QueuedTransport::with_retry_after(vec![(503, None, "{}"), (200, None, OK_JSON)]);
+    let limiter = RateLimiter::new(RateLimitConfig::new().capacity(2).refill_per_hour(1));
+    let client = CourtListenerClient::new(transport)
+        .with_retry_policy(policy())
+        .with_rate_limiter(limiter);
+
+    let _: serde_json::Value = client.fetch_json("/courts/", None).await.unwrap();
+    assert_eq!(client.rate_limit_remaining(), Some(0));
+
+    // The retry of a throttled request is refused by the limiter, not sent
+    let transport = QueuedTransport::with_retry_after(vec![(429, None, "{}")]);
+    let limiter = RateLimiter::new(RateLimitConfig::new().capacity(1).refill_per_hour(1));
+    let client = CourtListenerClient::new(transport)
+        .with_retry_policy(policy())
+        .with_rate_limiter(limiter);
+    let retried = client
+        .send(HttpRequest::new(
+            HttpMethod::Get,
+            "https://example.test/courts/",
+        ))
+        .await;
+    assert!(retried.result.unwrap_err().is_rate_limited());
+    assert_eq!(retried.attempts, 1);
+    assert_eq!(client.transport().sent(), 1);
+}
@@ -0,0 +1,106 @@
+//! Tests for the search API query builder
+
+use courtlistener_worker::errors::CourtListenerError;
+use courtlistener_worker::query::{SearchField, SearchOrder, SearchQuery, ToQuery};
+use courtlistener_worker::SearchType;
+
+#[test]
+fn test_default_is_case_law() {
+    let query = SearchQuery::new().q("constitution");
+    assert_eq!(query.selected_type(), SearchType::Opinions);
+    assert_eq!(query.to_query(), "type=o&q=constitution");
+}
+
+#[test]
+fn test_all_parameters() {
+    let query = SearchQuery::new()
+        .search_type(SearchType::RecapDocuments)
+        .q("motion to dismiss")
+        .field(SearchField::DocketNumber, "1:21-cv-00001")
+        .courts(["dcd", "nysd"])
+        .filed_after("2021-01-01")
+        .filed_before("2021-12-31")
+        .order_by(SearchOrder::EntryDateFiledDesc)
+        .highlight(true)
+        .page_size(10);
+
+    assert!(query.validate_query().is_ok());
+    assert_eq!(
+        query.to_query(),
+        "type=rd&q=%28motion%20to%20dismiss%29%20AND%20docketNumber%3A%281%3A21-cv-00001%29\
+         &court=dcd%20nysd&filed_after=2021-01-01&filed_before=2021-12-31\
+         &order_by=entry_date_filed%20desc&highlight=on&page_size=10"
+    );
+}
+
+#[test]
+fn test_raw_query_is_grouped_with_field_terms() {
+    let query = SearchQuery::new()
+        .q("roe OR wade")
+        .field(SearchField::CaseName, "x");
+    assert_eq!(
+        query.to_query(),
+        "type=o&q=%28roe%20OR%20wade%29%20AND%20caseName%3A%28x%29"
+    );
+}
+
+#[test]
+fn test_semantic_only_for_case_law() {
+    let query = SearchQuery::new()
+        .search_type(SearchType::OralArguments)
+        .q("privacy")
+        .semantic(true);
+    assert!(matches!(
+        query.validate_query(),
+        Err(CourtListenerError::InvalidRequest(_))
+    ));
+
+    let query = SearchQuery::new().q("privacy").semantic(true);
+    assert!(query.validate_query().is_ok());
+    assert_eq!(query.to_query(), "type=o&q=privacy&semantic=true");
+}
+
+#[test]
+fn test_semantic_requires_query() {
+    let query = SearchQuery::new().semantic(true);
+    assert!(query.validate_query().is_err());
+}
+
+#[test]
+fn test_order_must_match_type() {
+    let query = SearchQuery::new()
+        .search_type(SearchType::People)
+        .order_by(SearchOrder::CiteCountDesc);
+    assert!(query.validate_query().is_err());
+
+    let query = SearchQuery::new()
+        .search_type(SearchType::People)
+        .order_by(SearchOrder::NameAsc);
+    assert!(query.validate_query().is_ok());
+}
+
+#[test]
+fn test_date_range_order() {
+    let query = SearchQuery::new()
+        .filed_after("2022-01-01")
+        .filed_before("2021-01-01");
+    assert!(query.validate_query().is_err());
+}
+
+#[test]
+fn test_search_type_params_round_trip() {
+    for search_type in [
+        SearchType::Opinions,
+        SearchType::Recap,
+        SearchType::RecapDocuments,
+        SearchType::Dockets,
+        SearchType::People,
+        SearchType::OralArguments,
+    ] {
+        assert_eq!(
+            SearchType::from_param(search_type.as_str()),
+            Some(search_type)
+        );
+    }
+    assert_eq!(SearchType::from_param("x"), None);
+}
@@ -0,0 +1,134 @@
+//! Tests for per-type search results and SearchResponse
+
+use courtlistener_worker::query::search_type_of;
+use courtlistener_worker::{SearchResponse, SearchType};
+
+const OPINIONS_JSON: &str = r#"{
+    "count": 1,
+    "next": "https://www.courtlistener.com/api/rest/v4/search/?cursor=abc&type=o",
+    "previous": null,
+    "results": [{
+        "cluster_id": 2812209,
+        "caseName": "Obergefell v. Hodges",
+        "citation": ["576 U.S. 644"],
+        "citeCount": 1543,
+        "court_id": "scotus",
+        "dateFiled": "2015-06-26",
+        "docketNumber": "14-556",
+        "opinions": [{
+            "id": 2812209,
+            "author_id": 3200,
+            "cites": [100, 200],
+            "snippet": "The Fourteenth Amendment requires a State...",
+            "type": "lead-opinion"
+        }]
+    }]
+}"#;
+
+const RECAP_JSON: &str = r#"{
+    "count": 1,
+    "next": null,
+    "previous": null,
+    "results": [{
+        "docket_id": 4214664,
+        "caseName": "National Veterans Legal Services Program v. United States",
+        "court_id": "dcd",
+        "party": ["NVLSP"],
+        "more_docs": true,
+        "recap_documents": [{
+            "id": 28476,
+            "document_number": "1",
+            "description": "COMPLAINT",
+            "is_available": true,
+            "page_count": 24
+        }]
+    }]
+}"#;
+
+const JUDGES_JSON: &str = r#"{
+    "count": 1,
+    "next": null,
+    "previous": null,
+    "results": [{
+        "id": 1213,
+        "name": "Ruth Bader Ginsburg",
+        "dob": "1933-03-15",
+        "political_affiliation": ["Democratic"],
+        "positions": [{"court": "Supreme Court of the United States", "date_start": "1993-08-10"}]
+    }]
+}"#;
+
+#[test]
+fn test_opinion_results_keep_nested_opinions() {
+    let response = SearchResponse::from_json(SearchType::Opinions, OPINIONS_JSON).unwrap();
+    assert_eq!(response.search_type(), SearchType::Opinions);
+    assert_eq!(response.count(), 1);
+    assert!(response.next().unwrap().contains("cursor=abc"));
+
+    let SearchResponse::Opinions(page) = response else {
+        panic!("expected case law results");
+    };
+    let cluster = &page.results[0];
+    assert_eq!(cluster.case_name.as_deref(), Some("Obergefell v. Hodges"));
+    assert_eq!(cluster.cite_count, Some(1543));
+    let opinion = &cluster.opinions.as_ref().unwrap()[0];
+    assert_eq!(opinion.author_id, Some(3200));
+    assert_eq!(opinion.r#type.as_deref(), Some("lead-opinion"));
+}
+
+#[test]
+fn test_recap_results_keep_nested_documents() {
+    let SearchResponse::Recap(page) =
+        SearchResponse::from_json(SearchType::Recap, RECAP_JSON).unwrap()
+    else {
+        panic!("expected RECAP results");
+    };
+    let docket = &page.results[0];
+    assert_eq!(docket.docket_id, 4214664);
+    assert_eq!(docket.more_docs, Some(true));
+    let document = &docket.recap_documents.as_ref().unwrap()[0];
+    assert_eq!(document.description.as_deref(), Some("COMPLAINT"));
+    assert_eq!(document.page_count, Some(24));
+}
+
+#[test]
+fn test_judge_results_have_typed_positions() {
+    let SearchResponse::People(page) =
+        SearchResponse::from_json(SearchType::People, JUDGES_JSON).unwrap()
+    else {
+        panic!("expected judge results");
+    };
+    let judge = &page.results[0];
+    assert_eq!(judge.name.as_deref(), Some("Ruth Bader Ginsburg"));
+    let position = &judge.positions.as_ref().unwrap()[0];
+    assert_eq!(position.date_start.as_deref(), Some("1993-08-10"));
+}
+
+#[test]
+fn test_wrong_type_is_rejected() {
+    // Judge results have no cluster_id, so they are not valid case law results
+    assert!(SearchResponse::from_json(SearchType::Opinions, JUDGES_JSON).is_err());
+}
+
+#[test]
+fn test_serializes_with_api_field_names() {
+    let response = SearchResponse::from_json(SearchType::Opinions, OPINIONS_JSON).unwrap();
+    let value = serde_json::to_value(&response).unwrap();
+    assert_eq!(value["count"], 1);
+    assert_eq!(value["results"][0]["caseName"], "Obergefell v. Hodges");
+    assert_eq!(value["results"][0]["opinions"][0]["type"], "lead-opinion");
+}
+
+#[test]
+fn test_search_type_of_query() {
+    assert_eq!(search_type_of("q=test").unwrap(), SearchType::Opinions);
+    assert_eq!(
+        search_type_of("?q=test&type=oa").unwrap(),
+        SearchType::OralArguments
+    );
+    assert_eq!(
+        search_type_of("type=rd").unwrap(),
+        SearchType::RecapDocuments
+    );
+    assert!(search_type_of("type=x").is_err());
+}
@@ -202,3 +202,260 @@ fn test_docket() {
     assert_eq!(docket.case_name, Some("Roe v. Wade".to_string()));
     assert_eq!(docket.docket_number, Some("70-18".to_string()));
 }
+
+#[test]
+fn test_docket_entry_with_recap_documents() {
+    let response_json = r#"
+    {
+        "count": 1,
+        "next": null,
+        "previous": null,
+        "results": [
+            {
+                "id": 85001321,
+                "docket": "https://www.courtlistener.com/api/rest/v4/dockets/4214664/",
+                "entry_number": 1,
+                "date_filed": "2016-03-11",
+                "description": "COMPLAINT against Apple Inc.",
+                "recap_documents": [
+                    {
+                        "id": 21001,
+                        "document_type": 1,
+                        "document_number": "1",
+                        "attachment_number": null,
+                        "is_available": true,
+                        "page_count": 23
+                    },
+                    {
+                        "id": 21002,
+                        "document_type": 2,
+                        "document_number": "1",
+                        "attachment_number": 1,
+                        "is_available": false
+                    }
+                ]
+            }
+        ]
+    }
+    "#;
+
+    let response: DocketEntriesResponse = serde_json::from_str(response_json).unwrap();
+    let entry = &response.results[0];
+    assert_eq!(entry.entry_number, Some(1));
+    let documents = entry.recap_documents.as_ref().unwrap();
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].page_count, Some(23));
+    assert_eq!(documents[1].attachment_number, Some(1));
+}
+
+#[test]
+fn test_party_and_attorney_roles() {
+    let party_json = r#"
+    {
+        "id": 7,
+        "name": "Apple Inc.",
+        "attorneys": [
+            {
+                "attorney": "https://www.courtlistener.com/api/rest/v4/attorneys/9/",
+                "attorney_id": 9,
+                "date_action": null,
+                "docket_id": 4214664,
+                "role": 2
+            }
+        ],
+        "party_types": [
+            {
+                "docket_id": 4214664,
+                "name": "Defendant",
+                "criminal_counts": [],
+                "criminal_complaints": []
+            }
+        ]
+    }
+    "#;
+    let attorney_json = r#"
+    {
+        "id": 9,
+        "name": "Theodore J. Boutrous",
+        "parties_represented": [
+            {
+                "role": 4,
+                "docket": "https://www.courtlistener.com/api/rest/v4/dockets/4214664/",
+                "party": "https://www.courtlistener.com/api/rest/v4/parties/7/"
+            }
+        ]
+    }
+    "#;
+
+    let party: Party = serde_json::from_str(party_json).unwrap();
+    let counsel = &party.attorneys.as_ref().unwrap()[0];
+    assert_eq!(counsel.attorney_id, Some(9));
+    assert_eq!(counsel.role_name(), Some("Lead attorney"));
+    assert_eq!(
+        party.party_types.as_ref().unwrap()[0].name,
+        Some("Defendant".to_string())
+    );
+
+    let attorney: Attorney = serde_json::from_str(attorney_json).unwrap();
+    let role = &attorney.parties_represented.as_ref().unwrap()[0];
+    assert_eq!(role.role_name(), Some("Pro hac vice"));
+    assert_eq!(attorney_role_name(99), None);
+}
+
+#[test]
+fn test_person_with_people_db_relations() {
+    let person_json = r#"
+    {
+        "id": 1213,
+        "name_first": "Ruth",
+        "name_middle": "Bader",
+        "name_last": "Ginsburg",
+        "fjc_id": 844,
+        "date_dob": "1933-03-15",
+        "dob_city": "Brooklyn",
+        "dob_state": "NY",
+        "date_dod": "2020-09-18",
+        "gender": "f",
+        "race": ["w"],
+        "positions": [
+            {
+                "id": 2,
+                "court": "https://www.courtlistener.com/api/rest/v4/courts/scotus/",
+                "appointer": "https://www.courtlistener.com/api/rest/v4/positions/3/",
+                "position_type": "ajud",
+                "date_nominated": "1993-06-14",
+                "date_confirmation": "1993-08-03",
+                "date_termination": "2020-09-18",
+                "termination_reason": "ded",
+                "how_selected": "a_pres",
+                "votes_yes": 96,
+                "votes_no": 3,
+                "retention_events": []
+            }
+        ],
+        "educations": [
+            {
+                "id": 5,
+                "degree_level": "jd",
+                "degree_year": 1959,
+                "school": {"id": 9, "name": "Columbia University", "ein": 135598093}
+            }
+        ],
+        "political_affiliations": [{"id": 4, "political_party": "d", "source": "a"}],
+        "aba_ratings": [{"id": 6, "year_rated": 1993, "rating": "wq"}],
+        "sources": []
+    }
+    "#;
+
+    let person: ApiPerson = serde_json::from_str(person_json).unwrap();
+    assert_eq!(person.fjc_id, Some(844));
+    assert_eq!(person.race, Some(vec!["w".to_string()]));
+
+    let position = &person.positions.as_ref().unwrap()[0];
+    assert_eq!(position.termination_reason, Some("ded".to_string()));
+    assert_eq!(position.votes_yes, Some(96));
+
+    let education = &person.educations.as_ref().unwrap()[0];
+    let school = education.school.as_ref().unwrap();
+    assert_eq!(school.name, Some("Columbia University".to_string()));
+    assert_eq!(education.degree_year, Some(1959));
+
+    assert_eq!(
+        person.aba_ratings.as_ref().unwrap()[0].rating,
+        Some("wq".to_string())
+    );
+}
+
+#[test]
+fn test_financial_disclosure_value_codes() {
+    let disclosure_json = r#"
+    {
+        "id": 31000,
+        "person": "https://www.courtlistener.com/api/rest/v4/people/1213/",
+        "year": 2019,
+        "report_type": 2,
+        "investments": [
+            {
+                "id": 1,
+                "description": "Vanguard 500 Index Fund",
+                "income_during_reporting_period_code": "C",
+                "income_during_reporting_period_type": "Dividend",
+                "gross_value_code": "P1",
+                "gross_value_method": "T",
+                "transaction_value_code": ""
+            }
+        ],
+        "debts": [{"id": 2, "creditor_name": "Bank", "value_code": "K"}],
+        "gifts": [{"id": 3, "source": "Friend", "value": "$500.00"}]
+    }
+    "#;
+
+    let disclosure: FinancialDisclosure = serde_json::from_str(disclosure_json).unwrap();
+    let investment = &disclosure.investments.as_ref().unwrap()[0];
+    assert_eq!(investment.gross_value_code, Some(GrossValueCode::P1));
+    assert_eq!(investment.gross_value_method, Some(ValueMethodCode::T));
+    assert_eq!(
+        investment.income_during_reporting_period_code,
+        Some(IncomeCode::C)
+    );
+    assert_eq!(
+        investment.transaction_value_code,
+        Some(GrossValueCode::Unknown)
+    );
+    assert_eq!(
+        GrossValueCode::P1.range(),
+        Some((1_000_001, Some(5_000_000)))
+    );
+    assert_eq!(IncomeCode::H2.range(), Some((5_000_001, None)));
+
+    let debt = &disclosure.debts.as_ref().unwrap()[0];
+    assert_eq!(debt.value_code, Some(GrossValueCode::K));
+}
+
+#[test]
+fn test_docket_idb_data_decodes_codes() {
+    let docket_json = r#"
+    {
+        "id": 4214664,
+        "case_name": "National Veterans Legal Services Program v. United States",
+        "idb_data": {
+            "id": 5478,
+            "dataset_source": 1,
+            "office": "1",
+            "docket_number": "1600745",
+            "origin": 9,
+            "jurisdiction": 2,
+            "nature_of_suit": 890,
+            "class_action": true,
+            "procedural_progress": 4,
+            "disposition": 13,
+            "nature_of_judgement": -8,
+            "judgment": 3,
+            "pro_se": 0
+        }
+    }
+    "#;
+
+    let docket: Docket = serde_json::from_str(docket_json).unwrap();
+    let idb = docket.idb_data.unwrap();
+    assert_eq!(idb.dataset_source, Some(DatasetSource::Civil));
+    assert_eq!(idb.origin, Some(Origin::Reopened(3)));
+    assert_eq!(
+        idb.jurisdiction,
+        Some(JurisdictionBasis::GovernmentDefendant)
+    );
+    assert_eq!(idb.nature_of_suit, Some(890));
+    assert_eq!(
+        idb.procedural_progress,
+        Some(ProceduralProgress::JudgmentOnMotion)
+    );
+    assert_eq!(idb.disposition, Some(Disposition::Settled));
+    assert_eq!(idb.nature_of_judgement, Some(NatureOfJudgement::Missing));
+    assert_eq!(idb.judgment, Some(Judgment::Both));
+
+    let value = serde_json::to_value(&idb).unwrap();
+    assert_eq!(value["origin"], 9);
+    assert_eq!(value["nature_of_judgement"], -8);
+    let unknown: Disposition = serde_json::from_str("99").unwrap();
+    assert_eq!(unknown, Disposition::Other(99));
+}